@@ -0,0 +1,293 @@
+//! `kitties_*` RPC methods, answered by the runtime's `KittiesApi`.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use pallet_kittiesx::{CollectionKittyId, KittyInfo};
+use pallet_kittiesx_runtime_api::KittiesApi as KittiesRuntimeApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+const RUNTIME_ERROR: i32 = 1;
+
+/// Kitty query RPC methods. Each one reads the state at `at`, or at the best block if `None`.
+#[rpc(server)]
+pub trait KittiesApi<BlockHash, AccountId, Balance> {
+	/// A kitty with its owner and sale price.
+	#[method(name = "kitties_getKitty")]
+	fn kitty(
+		&self,
+		kitty_id: CollectionKittyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<KittyInfo<AccountId, Balance>>>;
+
+	/// Up to `limit` kitties of `owner`, skipping the first `start`.
+	#[method(name = "kitties_byOwner")]
+	fn kitties_by_owner(
+		&self,
+		owner: AccountId,
+		start: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CollectionKittyId>>;
+
+	/// Up to `limit` kitties on sale with their price, continuing after `start_after`.
+	#[method(name = "kitties_onSale")]
+	fn kitties_on_sale(
+		&self,
+		start_after: Option<CollectionKittyId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(CollectionKittyId, Balance)>>;
+
+	/// The parents of a bred kitty.
+	#[method(name = "kitties_getParents")]
+	fn kitty_parents(
+		&self,
+		kitty_id: CollectionKittyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(CollectionKittyId, CollectionKittyId)>>;
+}
+
+/// Serves [`KittiesApiServer`] through the client's runtime API.
+pub struct Kitties<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Kitties<C, Block> {
+	/// Create a new instance with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, Balance> KittiesApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+	for Kitties<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: KittiesRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec,
+	Balance: Codec,
+{
+	fn kitty(
+		&self,
+		kitty_id: CollectionKittyId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<KittyInfo<AccountId, Balance>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().kitty(at, kitty_id).map_err(runtime_error)
+	}
+
+	fn kitties_by_owner(
+		&self,
+		owner: AccountId,
+		start: u32,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<CollectionKittyId>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().kitties_by_owner(at, owner, start, limit).map_err(runtime_error)
+	}
+
+	fn kitties_on_sale(
+		&self,
+		start_after: Option<CollectionKittyId>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(CollectionKittyId, Balance)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().kitties_on_sale(at, start_after, limit).map_err(runtime_error)
+	}
+
+	fn kitty_parents(
+		&self,
+		kitty_id: CollectionKittyId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<(CollectionKittyId, CollectionKittyId)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().kitty_parents(at, kitty_id).map_err(runtime_error)
+	}
+}
+
+fn runtime_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query kitties",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use node_template_runtime::{opaque::Block, AccountId, Balance, BlockNumber};
+	use pallet_kittiesx::{Kitty, KittyLineage, KittyTraits};
+	use serde_json::{json, Value};
+	use sp_api::ApiRef;
+	use sp_blockchain::{BlockStatus, Info};
+	use sp_core::H256;
+	use sp_keyring::AccountKeyring;
+
+	type Hash = <Block as BlockT>::Hash;
+	type Header = <Block as BlockT>::Header;
+
+	const BEST_HASH: H256 = H256::repeat_byte(7);
+
+	fn owned() -> Vec<CollectionKittyId> {
+		vec![(0, 0), (0, 1), (0, 2)]
+	}
+
+	fn on_sale() -> Vec<(CollectionKittyId, Balance)> {
+		vec![((0, 0), 100), ((0, 2), 250)]
+	}
+
+	#[derive(Clone)]
+	struct MockRuntimeApi;
+
+	sp_api::mock_impl_runtime_apis! {
+		impl KittiesRuntimeApi<Block, AccountId, Balance> for MockRuntimeApi {
+			fn kitty_traits(_kitty_id: CollectionKittyId) -> Option<KittyTraits> {
+				None
+			}
+
+			fn kitty_ancestry(_kitty_id: CollectionKittyId, _depth: u32) -> Vec<KittyLineage> {
+				Vec::new()
+			}
+
+			fn kitty(kitty_id: CollectionKittyId) -> Option<KittyInfo<AccountId, Balance>> {
+				(kitty_id == (0, 0)).then(|| KittyInfo {
+					kitty: Kitty { dna: [1; 16], name: *b"tom     ", feature: *b"happy" },
+					owner: AccountKeyring::Alice.to_account_id(),
+					price: Some(100),
+				})
+			}
+
+			fn kitties_by_owner(owner: AccountId, start: u32, limit: u32) -> Vec<CollectionKittyId> {
+				if owner != AccountKeyring::Alice.to_account_id() {
+					return Vec::new()
+				}
+				owned().into_iter().skip(start as usize).take(limit as usize).collect()
+			}
+
+			fn kitties_on_sale(
+				start_after: Option<CollectionKittyId>,
+				limit: u32,
+			) -> Vec<(CollectionKittyId, Balance)> {
+				let skip = start_after.map_or(0, |cursor| {
+					on_sale().iter().position(|(id, _)| *id == cursor).map_or(0, |i| i + 1)
+				});
+				on_sale().into_iter().skip(skip).take(limit as usize).collect()
+			}
+
+			fn kitty_parents(kitty_id: CollectionKittyId) -> Option<(CollectionKittyId, CollectionKittyId)> {
+				(kitty_id == (0, 2)).then_some(((0, 0), (0, 1)))
+			}
+		}
+	}
+
+	struct MockClient;
+
+	impl ProvideRuntimeApi<Block> for MockClient {
+		type Api = MockRuntimeApi;
+
+		fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+			MockRuntimeApi.into()
+		}
+	}
+
+	impl HeaderBackend<Block> for MockClient {
+		fn header(&self, _hash: Hash) -> sp_blockchain::Result<Option<Header>> {
+			Ok(None)
+		}
+
+		fn info(&self) -> Info<Block> {
+			Info {
+				best_hash: BEST_HASH,
+				best_number: 1,
+				genesis_hash: Default::default(),
+				finalized_hash: Default::default(),
+				finalized_number: 0,
+				finalized_state: None,
+				number_leaves: 1,
+				block_gap: None,
+			}
+		}
+
+		fn status(&self, _hash: Hash) -> sp_blockchain::Result<BlockStatus> {
+			Ok(BlockStatus::InChain)
+		}
+
+		fn number(&self, _hash: Hash) -> sp_blockchain::Result<Option<BlockNumber>> {
+			Ok(Some(1))
+		}
+
+		fn hash(&self, _number: BlockNumber) -> sp_blockchain::Result<Option<Hash>> {
+			Ok(Some(BEST_HASH))
+		}
+	}
+
+	fn rpc() -> jsonrpsee::RpcModule<Kitties<MockClient, Block>> {
+		KittiesApiServer::<Hash, AccountId, Balance>::into_rpc(Kitties::new(Arc::new(MockClient)))
+	}
+
+	#[tokio::test]
+	async fn get_kitty_returns_kitty_with_owner_and_price() {
+		let kitty: Option<KittyInfo<AccountId, Balance>> =
+			rpc().call("kitties_getKitty", [json!([0, 0]), Value::Null]).await.unwrap();
+		let kitty = kitty.unwrap();
+		assert_eq!(kitty.kitty.name, *b"tom     ");
+		assert_eq!(kitty.owner, AccountKeyring::Alice.to_account_id());
+		assert_eq!(kitty.price, Some(100));
+
+		let missing: Option<KittyInfo<AccountId, Balance>> =
+			rpc().call("kitties_getKitty", [json!([0, 9]), json!(BEST_HASH)]).await.unwrap();
+		assert_eq!(missing, None);
+	}
+
+	#[tokio::test]
+	async fn by_owner_pages_through_owned_kitties() {
+		let alice = json!(AccountKeyring::Alice.to_account_id());
+		let first: Vec<CollectionKittyId> = rpc()
+			.call("kitties_byOwner", [alice.clone(), json!(0), json!(2), Value::Null])
+			.await
+			.unwrap();
+		assert_eq!(first, vec![(0, 0), (0, 1)]);
+		let second: Vec<CollectionKittyId> =
+			rpc().call("kitties_byOwner", [alice, json!(2), json!(2), Value::Null]).await.unwrap();
+		assert_eq!(second, vec![(0, 2)]);
+
+		let bob = json!(AccountKeyring::Bob.to_account_id());
+		let none: Vec<CollectionKittyId> =
+			rpc().call("kitties_byOwner", [bob, json!(0), json!(10), Value::Null]).await.unwrap();
+		assert!(none.is_empty());
+	}
+
+	#[tokio::test]
+	async fn on_sale_continues_after_cursor() {
+		let first: Vec<(CollectionKittyId, Balance)> =
+			rpc().call("kitties_onSale", [Value::Null, json!(1), Value::Null]).await.unwrap();
+		assert_eq!(first, vec![((0, 0), 100)]);
+		let rest: Vec<(CollectionKittyId, Balance)> =
+			rpc().call("kitties_onSale", [json!([0, 0]), json!(10), Value::Null]).await.unwrap();
+		assert_eq!(rest, vec![((0, 2), 250)]);
+	}
+
+	#[tokio::test]
+	async fn get_parents_of_bred_kitty() {
+		let parents: Option<(CollectionKittyId, CollectionKittyId)> =
+			rpc().call("kitties_getParents", [json!([0, 2]), Value::Null]).await.unwrap();
+		assert_eq!(parents, Some(((0, 0), (0, 1))));
+		let parents: Option<(CollectionKittyId, CollectionKittyId)> =
+			rpc().call("kitties_getParents", [json!([0, 0]), Value::Null]).await.unwrap();
+		assert_eq!(parents, None);
+	}
+}
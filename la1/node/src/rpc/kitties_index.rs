@@ -0,0 +1,78 @@
+//! `kittiesIndex_*` RPC methods, answered from the kitty index that the kittiesx offchain
+//! worker keeps in persistent offchain storage.
+//!
+//! The index is only filled on nodes that run the worker for every imported block, i.e. that
+//! are started with `--offchain-worker always`.
+
+use codec::Decode;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use node_template_runtime::{AccountId, Balance};
+use pallet_kittiesx::{
+	index::{self, TraitFilter},
+	CollectionKittyId,
+};
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+
+/// Kitty index RPC methods.
+#[rpc(server)]
+pub trait KittiesIndexApi {
+	/// Kitties held by `owner`.
+	#[method(name = "kittiesIndex_byOwner")]
+	fn by_owner(&self, owner: AccountId) -> RpcResult<Vec<CollectionKittyId>>;
+
+	/// Kitties showing the given trait.
+	#[method(name = "kittiesIndex_byTrait")]
+	fn by_trait(&self, filter: TraitFilter) -> RpcResult<Vec<CollectionKittyId>>;
+
+	/// Kitties on sale with their price, cheapest first, up to `max_price` if given.
+	#[method(name = "kittiesIndex_onSale")]
+	fn on_sale(&self, max_price: Option<Balance>) -> RpcResult<Vec<(CollectionKittyId, Balance)>>;
+}
+
+/// Serves [`KittiesIndexApi`] from the node's offchain storage.
+pub struct KittiesIndex<S> {
+	storage: Option<S>,
+}
+
+impl<S> KittiesIndex<S> {
+	/// Create a new instance, `storage` is `None` when the node has no offchain storage.
+	pub fn new(storage: Option<S>) -> Self {
+		Self { storage }
+	}
+}
+
+impl<S: OffchainStorage> KittiesIndex<S> {
+	fn read<V: Decode + Default>(&self, key: &[u8]) -> RpcResult<V> {
+		let storage = self.storage.as_ref().ok_or_else(|| error("Offchain storage is disabled"))?;
+		match storage.get(STORAGE_PREFIX, key) {
+			Some(raw) => V::decode(&mut &raw[..]).map_err(|_| error("Index entry does not decode")),
+			None => Ok(V::default()),
+		}
+	}
+}
+
+impl<S: OffchainStorage + 'static> KittiesIndexApiServer for KittiesIndex<S> {
+	fn by_owner(&self, owner: AccountId) -> RpcResult<Vec<CollectionKittyId>> {
+		self.read(&index::owner_key(&owner))
+	}
+
+	fn by_trait(&self, filter: TraitFilter) -> RpcResult<Vec<CollectionKittyId>> {
+		self.read(&index::trait_key(&filter))
+	}
+
+	fn on_sale(&self, max_price: Option<Balance>) -> RpcResult<Vec<(CollectionKittyId, Balance)>> {
+		let mut on_sale: Vec<(CollectionKittyId, Balance)> = self.read(index::ON_SALE_KEY)?;
+		if let Some(max_price) = max_price {
+			on_sale.retain(|(_, price)| *price <= max_price);
+		}
+		Ok(on_sale)
+	}
+}
+
+fn error(message: &str) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(1, message, None::<()>)).into()
+}
@@ -0,0 +1,27 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_kittiesx::{CollectionKittyId, KittyInfo, KittyLineage, KittyTraits};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Version 2 added the kitty, owner, sale and parent queries.
+	#[api_version(2)]
+	pub trait KittiesApi<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		fn kitty_traits(kitty_id: CollectionKittyId) -> Option<KittyTraits>;
+		fn kitty_ancestry(kitty_id: CollectionKittyId, depth: u32) -> Vec<KittyLineage>;
+		fn kitty(kitty_id: CollectionKittyId) -> Option<KittyInfo<AccountId, Balance>>;
+		/// Pages through the kitties of `owner`, at most `pallet_kittiesx::MAX_PAGE_SIZE` each.
+		fn kitties_by_owner(owner: AccountId, start: u32, limit: u32) -> Vec<CollectionKittyId>;
+		/// Pages through the kitties on sale; pass the last kitty of a page to get the next one.
+		fn kitties_on_sale(
+			start_after: Option<CollectionKittyId>,
+			limit: u32,
+		) -> Vec<(CollectionKittyId, Balance)>;
+		fn kitty_parents(kitty_id: CollectionKittyId) -> Option<(CollectionKittyId, CollectionKittyId)>;
+	}
+}
@@ -1,8 +1,575 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-kittiesx
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
+use crate::Pallet as KittiesxModule;
 use frame_benchmarking::v2::*;
+use frame_support::{traits::Currency, BoundedVec};
 use frame_system::RawOrigin;
+use sp_runtime::{
+	traits::{Bounded, Saturating},
+	Perbill,
+};
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	caller
+}
+
+fn mint_price<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance().saturating_mul(10u32.into())
+}
+
+fn ensure_collection<T: Config>() {
+	if Collections::<T>::contains_key(DEFAULT_COLLECTION_ID) {
+		return;
+	}
+	let collection = Collection {
+		owner: account("creator", 0, SEED),
+		metadata: Default::default(),
+		max_supply: None,
+		mint_price: mint_price::<T>(),
+	};
+	Collections::<T>::insert(DEFAULT_COLLECTION_ID, collection);
+	NextCollectionId::<T>::put(DEFAULT_COLLECTION_ID + 1);
+}
+
+fn next_kitty_id<T: Config>() -> CollectionKittyId {
+	(DEFAULT_COLLECTION_ID, NextKittyId::<T>::get(DEFAULT_COLLECTION_ID))
+}
+
+fn create_kitty<T: Config>(owner: &T::AccountId) -> CollectionKittyId {
+	ensure_collection::<T>();
+	let kitty_id = next_kitty_id::<T>();
+	let _ = KittiesxModule::<T>::create(
+		RawOrigin::Signed(owner.clone()).into(),
+		DEFAULT_COLLECTION_ID,
+		Perbill::zero(),
+	);
+	kitty_id
+}
+
+fn metadata<T: Config>() -> CollectionMetadata<T> {
+	BoundedVec::truncate_from(vec![b'x'; T::MaxMetadataLength::get() as usize])
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		ensure_collection::<T>();
+		let kitty_id = next_kitty_id::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), DEFAULT_COLLECTION_ID, T::MaxRoyalty::get());
+
+		assert_eq!(KittyOwners::<T>::get(kitty_id), Some(caller));
+	}
+
+	#[benchmark]
+	fn breed() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		// worst case: both parents are bred kitties that have bred before
+		let mut parents = [(DEFAULT_COLLECTION_ID, 0); 2];
+		for parent in parents.iter_mut() {
+			let grand_parent_1 = create_kitty::<T>(&caller);
+			let grand_parent_2 = create_kitty::<T>(&caller);
+			*parent = next_kitty_id::<T>();
+			let _ = KittiesxModule::<T>::breed(
+				RawOrigin::Signed(caller.clone()).into(),
+				DEFAULT_COLLECTION_ID,
+				grand_parent_1,
+				grand_parent_2,
+				Perbill::zero(),
+			);
+		}
+		let [kitty_id_1, kitty_id_2] = parents;
+		KittyLastBred::<T>::insert(kitty_id_1, frame_system::Pallet::<T>::block_number());
+		KittyLastBred::<T>::insert(kitty_id_2, frame_system::Pallet::<T>::block_number());
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::BreedCooldown::get(),
+		);
+		let kitty_id = next_kitty_id::<T>();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			DEFAULT_COLLECTION_ID,
+			kitty_id_1,
+			kitty_id_2,
+			T::MaxRoyalty::get(),
+		);
+
+		assert_eq!(KittyParents::<T>::get(kitty_id), Some((kitty_id_1, kitty_id_2)));
+	}
+
+	#[benchmark]
+	fn transfer() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let kitty_id = create_kitty::<T>(&caller);
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), recipient.clone(), kitty_id);
+
+		assert_eq!(KittyOwners::<T>::get(kitty_id), Some(recipient));
+	}
+
+	#[benchmark]
+	fn sale() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let kitty_id = create_kitty::<T>(&caller);
+		// worst case: moving the kitty into escrow releases its metadata deposit
+		let name = KittyName::try_from(b"kittyxyz".to_vec()).unwrap();
+		let _ =
+			KittiesxModule::<T>::set_name(RawOrigin::Signed(caller.clone()).into(), kitty_id, name);
+		let price = mint_price::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), kitty_id, price);
+
+		assert_eq!(KittyOnSale::<T>::get(kitty_id), Some(price));
+		assert_eq!(
+			KittyOwners::<T>::get(kitty_id),
+			Some(KittiesxModule::<T>::escrow_account(kitty_id))
+		);
+	}
+
+	#[benchmark]
+	fn buy() {
+		// worst case: the creator is paid a royalty on top of the seller and the fee
+		let creator = funded_account::<T>("creator", 0);
+		ensure_collection::<T>();
+		let kitty_id = next_kitty_id::<T>();
+		let _ = KittiesxModule::<T>::create(
+			RawOrigin::Signed(creator.clone()).into(),
+			DEFAULT_COLLECTION_ID,
+			T::MaxRoyalty::get(),
+		);
+		let seller = funded_account::<T>("seller", 0);
+		let _ = KittiesxModule::<T>::transfer(
+			RawOrigin::Signed(creator).into(),
+			seller.clone(),
+			kitty_id,
+		);
+		let price = mint_price::<T>();
+		let _ = KittiesxModule::<T>::sale(RawOrigin::Signed(seller).into(), kitty_id, price);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), kitty_id, price);
+
+		assert_eq!(KittyOwners::<T>::get(kitty_id), Some(caller));
+	}
+
+	#[benchmark]
+	fn cancel_sale() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let kitty_id = create_kitty::<T>(&caller);
+		let price = mint_price::<T>();
+		let _ =
+			KittiesxModule::<T>::sale(RawOrigin::Signed(caller.clone()).into(), kitty_id, price);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), kitty_id);
+
+		assert_eq!(KittyOnSale::<T>::get(kitty_id), None);
+		assert_eq!(KittyOwners::<T>::get(kitty_id), Some(caller));
+	}
+
+	#[benchmark]
+	fn update_price() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let kitty_id = create_kitty::<T>(&caller);
+		let price = mint_price::<T>();
+		let _ =
+			KittiesxModule::<T>::sale(RawOrigin::Signed(caller.clone()).into(), kitty_id, price);
+		let new_price = price.saturating_add(price);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), kitty_id, new_price);
+
+		assert_eq!(KittyOnSale::<T>::get(kitty_id), Some(new_price));
+	}
+
+	#[benchmark]
+	fn create_auction() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let kitty_id = create_kitty::<T>(&caller);
+		let price = mint_price::<T>();
+		let end = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), kitty_id, price, end);
+
+		assert!(KittyAuctions::<T>::contains_key(kitty_id));
+	}
+
+	#[benchmark]
+	fn bid() {
+		let seller = funded_account::<T>("seller", 0);
+		let kitty_id = create_kitty::<T>(&seller);
+		let price = mint_price::<T>();
+		let end = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let _ = KittiesxModule::<T>::create_auction(
+			RawOrigin::Signed(seller).into(),
+			kitty_id,
+			price,
+			end,
+		);
+		let bidder = funded_account::<T>("bidder", 0);
+		let _ = KittiesxModule::<T>::bid(RawOrigin::Signed(bidder).into(), kitty_id, price);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let amount = price.saturating_add(price);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), kitty_id, amount);
+
+		assert_eq!(KittyAuctions::<T>::get(kitty_id).unwrap().best_bid, Some((caller, amount)));
+	}
+
+	#[benchmark]
+	fn settle_auction() {
+		let seller = funded_account::<T>("seller", 0);
+		let kitty_id = create_kitty::<T>(&seller);
+		let price = mint_price::<T>();
+		let end = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let _ = KittiesxModule::<T>::create_auction(
+			RawOrigin::Signed(seller).into(),
+			kitty_id,
+			price,
+			end,
+		);
+		let bidder = funded_account::<T>("bidder", 0);
+		let _ = KittiesxModule::<T>::bid(RawOrigin::Signed(bidder.clone()).into(), kitty_id, price);
+
+		#[block]
+		{
+			KittiesxModule::<T>::settle_auction(kitty_id);
+		}
+
+		assert_eq!(KittyOwners::<T>::get(kitty_id), Some(bidder));
+	}
+
+	#[benchmark]
+	fn create_with_name() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		ensure_collection::<T>();
+		let kitty_id = next_kitty_id::<T>();
+		let name = KittyName::try_from(b"kittyxyz".to_vec()).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), DEFAULT_COLLECTION_ID, name, T::MaxRoyalty::get());
+
+		assert_eq!(Kitties::<T>::get(kitty_id).unwrap().name, *b"kittyxyz");
+	}
+
+	#[benchmark]
+	fn set_name() {
+		let seller = funded_account::<T>("seller", 0);
+		let kitty_id = create_kitty::<T>(&seller);
+		let name = KittyName::try_from(b"kittyxyz".to_vec()).unwrap();
+		let _ = KittiesxModule::<T>::set_name(
+			RawOrigin::Signed(seller.clone()).into(),
+			kitty_id,
+			name.clone(),
+		);
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let _ = KittiesxModule::<T>::transfer(
+			RawOrigin::Signed(seller).into(),
+			caller.clone(),
+			kitty_id,
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), kitty_id, name);
+
+		assert_eq!(MetadataDeposits::<T>::get(kitty_id).map(|d| d.0), Some(caller));
+	}
+
+	#[benchmark]
+	fn set_feature() {
+		let seller = funded_account::<T>("seller", 0);
+		let kitty_id = create_kitty::<T>(&seller);
+		let feature = KittyFeature::try_from(b"lucky".to_vec()).unwrap();
+		let _ = KittiesxModule::<T>::set_feature(
+			RawOrigin::Signed(seller.clone()).into(),
+			kitty_id,
+			feature.clone(),
+		);
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let _ = KittiesxModule::<T>::transfer(
+			RawOrigin::Signed(seller).into(),
+			caller.clone(),
+			kitty_id,
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), kitty_id, feature);
+
+		assert_eq!(MetadataDeposits::<T>::get(kitty_id).map(|d| d.0), Some(caller));
+	}
+
+	#[benchmark]
+	fn burn() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let kitty_id = create_kitty::<T>(&caller);
+		let name = KittyName::try_from(b"kittyxyz".to_vec()).unwrap();
+		let _ =
+			KittiesxModule::<T>::set_name(RawOrigin::Signed(caller.clone()).into(), kitty_id, name);
+		// only a listing from before escrow leaves the kitty with its owner to burn
+		KittyOnSale::<T>::insert(kitty_id, mint_price::<T>());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), kitty_id);
+
+		assert!(!Kitties::<T>::contains_key(kitty_id));
+	}
+
+	#[benchmark]
+	fn approve() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let kitty_id = create_kitty::<T>(&caller);
+		let spender: T::AccountId = account("spender", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), kitty_id, spender.clone());
+
+		assert_eq!(KittyApprovals::<T>::get(kitty_id), Some(spender));
+	}
+
+	#[benchmark]
+	fn set_approval_for_all() {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), operator.clone(), true);
+
+		assert!(OperatorApprovals::<T>::get(caller, operator));
+	}
+
+	#[benchmark]
+	fn transfer_from() {
+		let owner = funded_account::<T>("owner", 0);
+		let kitty_id = create_kitty::<T>(&owner);
+		let caller: T::AccountId = whitelisted_caller();
+		let _ = KittiesxModule::<T>::set_approval_for_all(
+			RawOrigin::Signed(owner.clone()).into(),
+			caller.clone(),
+			true,
+		);
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), owner, recipient.clone(), kitty_id);
+
+		assert_eq!(KittyOwners::<T>::get(kitty_id), Some(recipient));
+	}
+
+	#[benchmark]
+	fn commit_create() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		ensure_collection::<T>();
+		let kitty_id = next_kitty_id::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), DEFAULT_COLLECTION_ID, T::MaxRoyalty::get());
+
+		assert_eq!(PendingMints::<T>::get(kitty_id).unwrap().owner, caller);
+	}
+
+	#[benchmark]
+	fn reveal_mint() {
+		let caller = funded_account::<T>("caller", 0);
+		ensure_collection::<T>();
+		let kitty_id = next_kitty_id::<T>();
+		let _ = KittiesxModule::<T>::commit_create(
+			RawOrigin::Signed(caller.clone()).into(),
+			DEFAULT_COLLECTION_ID,
+			Perbill::zero(),
+		);
+		let reveal_at = frame_system::Pallet::<T>::block_number() + T::RevealDelay::get();
+		frame_system::Pallet::<T>::set_block_number(reveal_at);
+
+		#[block]
+		{
+			KittiesxModule::<T>::reveal_mint(kitty_id, reveal_at);
+		}
+
+		assert_eq!(KittyOwners::<T>::get(kitty_id), Some(caller));
+	}
+
+	#[benchmark]
+	fn create_collection() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = NextCollectionId::<T>::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), metadata::<T>(), Some(u32::MAX), mint_price::<T>());
+
+		assert_eq!(Collections::<T>::get(collection_id).map(|c| c.owner), Some(caller));
+	}
+
+	#[benchmark]
+	fn set_collection_metadata() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = NextCollectionId::<T>::get();
+		let _ = KittiesxModule::<T>::create_collection(
+			RawOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			None,
+			mint_price::<T>(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, metadata::<T>());
+
+		assert_eq!(Collections::<T>::get(collection_id).unwrap().metadata, metadata::<T>());
+	}
+
+	#[benchmark]
+	fn set_mint_price() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = NextCollectionId::<T>::get();
+		let _ = KittiesxModule::<T>::create_collection(
+			RawOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			None,
+			mint_price::<T>(),
+		);
+		let new_price = mint_price::<T>().saturating_add(mint_price::<T>());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, new_price);
+
+		assert_eq!(Collections::<T>::get(collection_id).unwrap().mint_price, new_price);
+	}
+
+	#[benchmark]
+	fn create_batch(c: Linear<1, { T::MaxBatchSize::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		ensure_collection::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), DEFAULT_COLLECTION_ID, c, T::MaxRoyalty::get());
+
+		assert_eq!(OwnedKitties::<T>::get(&caller).len() as u32, c);
+	}
+
+	#[benchmark]
+	fn transfer_batch(c: Linear<1, { T::MaxBatchSize::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		// worst case: every kitty goes to a different recipient
+		let transfers: Vec<_> = (0..c)
+			.map(|i| (account::<T::AccountId>("recipient", i, SEED), create_kitty::<T>(&caller)))
+			.collect();
+		let transfers = BoundedVec::try_from(transfers).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), transfers);
+
+		assert!(OwnedKitties::<T>::get(&caller).is_empty());
+	}
+
+	#[benchmark]
+	fn lend() {
+		let caller = funded_account::<T>("caller", 0);
+		let kitty_id = create_kitty::<T>(&caller);
+		let borrower: T::AccountId = account("borrower", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), kitty_id, borrower.clone(), 10u32.into());
+
+		assert_eq!(KittyLeases::<T>::get(kitty_id).unwrap().borrower, borrower);
+	}
+
+	#[benchmark]
+	fn return_kitty() {
+		let owner = funded_account::<T>("owner", 0);
+		let kitty_id = create_kitty::<T>(&owner);
+		let borrower = funded_account::<T>("borrower", 0);
+		// worst case: the lease shares its expiry block with as many others as allowed
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let others = (1..T::MaxLeasesPerBlock::get()).map(|i| (DEFAULT_COLLECTION_ID + 1, i));
+		LeasesExpiring::<T>::insert(expires_at, BoundedVec::truncate_from(others.collect()));
+		let _ = KittiesxModule::<T>::lend(
+			RawOrigin::Signed(owner).into(),
+			kitty_id,
+			borrower.clone(),
+			10u32.into(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(borrower), kitty_id);
+
+		assert!(!KittyLeases::<T>::contains_key(kitty_id));
+	}
+
+	#[benchmark]
+	fn end_lease() {
+		let owner = funded_account::<T>("owner", 0);
+		let kitty_id = create_kitty::<T>(&owner);
+		let borrower: T::AccountId = account("borrower", 0, SEED);
+		let _ = KittiesxModule::<T>::lend(
+			RawOrigin::Signed(owner).into(),
+			kitty_id,
+			borrower,
+			10u32.into(),
+		);
+
+		#[block]
+		{
+			KittiesxModule::<T>::end_lease(kitty_id);
+		}
+
+		assert!(!KittyLeases::<T>::contains_key(kitty_id));
+	}
+
+	#[benchmark]
+	fn cancel_approval() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let kitty_id = create_kitty::<T>(&caller);
+		let spender: T::AccountId = account("spender", 0, SEED);
+		let _ = KittiesxModule::<T>::approve(
+			RawOrigin::Signed(caller.clone()).into(),
+			kitty_id,
+			spender,
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), kitty_id);
+
+		assert_eq!(KittyApprovals::<T>::get(kitty_id), None);
+	}
+
+	impl_benchmark_test_suite!(KittiesxModule, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,206 @@
+//! Randomized sequences of create/breed/transfer/sale/buy over the mock runtime, checking the
+//! pallet's state invariants after every step.
+//!
+//! Every run is driven by a seed, and a failure reports the seed together with the actions
+//! that led to it. Rerun a single sequence with `KITTIES_FUZZ_SEED=<seed> cargo test fuzz`.
+
+use crate::{
+	mock::*, CollectionKittyId, Kitties, KittyId, KittyOnSale, KittyOwners, KittySellers,
+	OwnedKitties,
+};
+use frame_support::traits::{fungible::Mutate, Currency};
+use sp_runtime::{traits::AccountIdConversion, DispatchResult, Perbill};
+
+const ACCOUNTS: [u64; 4] = [1, 2, 3, 4];
+const INITIAL_BALANCE: u128 = 2_000;
+const RUNS: u64 = 64;
+const STEPS: usize = 150;
+
+/// SplitMix64, so sequences only depend on the seed and not on an external crate's algorithm.
+struct Rng(u64);
+
+impl Rng {
+	fn next(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+		z ^ (z >> 31)
+	}
+
+	fn below(&mut self, n: u64) -> u64 {
+		self.next() % n
+	}
+
+	fn account(&mut self) -> u64 {
+		ACCOUNTS[self.below(ACCOUNTS.len() as u64) as usize]
+	}
+
+	/// A minted kitty most of the time, sometimes one that does not exist (yet).
+	fn kitty_id(&mut self) -> CollectionKittyId {
+		let next = KittiesxModule::next_kitty_id(COLLECTION_ID) as u64;
+		(COLLECTION_ID, self.below(next + 2) as KittyId)
+	}
+}
+
+#[derive(Debug)]
+enum Action {
+	Create { who: u64, royalty: Perbill },
+	Breed { who: u64, kitty_id_1: CollectionKittyId, kitty_id_2: CollectionKittyId },
+	Transfer { who: u64, recipient: u64, kitty_id: CollectionKittyId },
+	Sale { who: u64, kitty_id: CollectionKittyId, price: u128 },
+	Buy { who: u64, kitty_id: CollectionKittyId, max_price: u128 },
+	NextBlock,
+}
+
+impl Action {
+	fn random(rng: &mut Rng) -> Self {
+		match rng.below(12) {
+			0..=2 => Action::Create {
+				who: rng.account(),
+				// now and then above `MaxRoyalty`
+				royalty: Perbill::from_percent(rng.below(13) as u32),
+			},
+			3 | 4 => Action::Breed {
+				who: rng.account(),
+				kitty_id_1: rng.kitty_id(),
+				kitty_id_2: rng.kitty_id(),
+			},
+			5 | 6 => Action::Transfer {
+				who: rng.account(),
+				recipient: rng.account(),
+				kitty_id: rng.kitty_id(),
+			},
+			7 | 8 => Action::Sale {
+				who: rng.account(),
+				kitty_id: rng.kitty_id(),
+				price: rng.below(INITIAL_BALANCE as u64) as u128,
+			},
+			9 | 10 => Action::Buy {
+				who: rng.account(),
+				kitty_id: rng.kitty_id(),
+				max_price: rng.below(INITIAL_BALANCE as u64) as u128,
+			},
+			_ => Action::NextBlock,
+		}
+	}
+
+	fn apply(&self) -> DispatchResult {
+		match *self {
+			Action::Create { who, royalty } => {
+				KittiesxModule::create(RuntimeOrigin::signed(who), COLLECTION_ID, royalty)
+			}
+			Action::Breed { who, kitty_id_1, kitty_id_2 } => KittiesxModule::breed(
+				RuntimeOrigin::signed(who),
+				COLLECTION_ID,
+				kitty_id_1,
+				kitty_id_2,
+				Perbill::zero(),
+			),
+			Action::Transfer { who, recipient, kitty_id } => {
+				KittiesxModule::transfer(RuntimeOrigin::signed(who), recipient, kitty_id)
+			}
+			Action::Sale { who, kitty_id, price } => {
+				KittiesxModule::sale(RuntimeOrigin::signed(who), kitty_id, price)
+			}
+			Action::Buy { who, kitty_id, max_price } => {
+				KittiesxModule::buy(RuntimeOrigin::signed(who), kitty_id, max_price)
+			}
+			Action::NextBlock => {
+				run_to_block(System::block_number() + 1);
+				Ok(())
+			}
+		}
+	}
+}
+
+fn holders() -> Vec<u64> {
+	let pallet_account = KittyPalletId::get().into_account_truncating();
+	ACCOUNTS.iter().copied().chain([COLLECTION_OWNER, pallet_account]).collect()
+}
+
+fn check_invariants(issuance: u128) -> Result<(), String> {
+	let next_id = KittiesxModule::next_kitty_id(COLLECTION_ID);
+	for kitty_id in Kitties::<Test>::iter_keys() {
+		let owner = KittiesxModule::kitty_owners(kitty_id)
+			.ok_or_else(|| format!("kitty {:?} has no owner", kitty_id))?;
+		let listed: Vec<_> = OwnedKitties::<Test>::iter()
+			.filter(|(_, owned)| owned.contains(&kitty_id))
+			.map(|(account, _)| account)
+			.collect();
+		if listed != vec![owner] {
+			return Err(format!(
+				"kitty {:?} owned by {} is listed under {:?}",
+				kitty_id, owner, listed
+			));
+		}
+		if kitty_id.1 >= next_id {
+			return Err(format!("kitty {:?} is not below NextKittyId {}", kitty_id, next_id));
+		}
+	}
+	for (kitty_id, owner) in KittyOwners::<Test>::iter() {
+		if KittiesxModule::kitties(kitty_id).is_none() {
+			return Err(format!("{} owns kitty {:?} that does not exist", owner, kitty_id));
+		}
+	}
+	for kitty_id in KittyOnSale::<Test>::iter_keys() {
+		if KittiesxModule::kitties(kitty_id).is_none() {
+			return Err(format!("kitty {:?} is on sale but does not exist", kitty_id));
+		}
+	}
+	for (kitty_id, seller) in KittySellers::<Test>::iter() {
+		if KittiesxModule::kitty_on_sale(kitty_id).is_none() {
+			return Err(format!(
+				"{} is the seller of kitty {:?} that is not on sale",
+				seller, kitty_id
+			));
+		}
+		if KittiesxModule::kitty_owners(kitty_id) != Some(KittiesxModule::escrow_account(kitty_id))
+		{
+			return Err(format!("kitty {:?} listed by {} is not held in escrow", kitty_id, seller));
+		}
+	}
+	let held: u128 = holders().iter().map(Balances::total_balance).sum();
+	if Balances::total_issuance() != issuance || held != issuance {
+		return Err(format!(
+			"issuance {} and balances held {} differ from the initial {}",
+			Balances::total_issuance(),
+			held,
+			issuance
+		));
+	}
+	Ok(())
+}
+
+fn run(seed: u64) {
+	new_test_ext().execute_with(|| {
+		for account in ACCOUNTS {
+			Balances::set_balance(&account, INITIAL_BALANCE);
+		}
+		let issuance = Balances::total_issuance();
+		let mut rng = Rng(seed);
+		let mut history = Vec::new();
+		for step in 0..STEPS {
+			let action = Action::random(&mut rng);
+			let result = action.apply();
+			history.push(format!("{:?} -> {:?}", action, result));
+			if let Err(violation) = check_invariants(issuance) {
+				panic!(
+					"seed {} broke an invariant at step {}: {}\n{}",
+					seed,
+					step,
+					violation,
+					history.join("\n")
+				);
+			}
+		}
+	});
+}
+
+#[test]
+fn fuzz_kitty_actions_keep_invariants() {
+	match std::env::var("KITTIES_FUZZ_SEED") {
+		Ok(seed) => run(seed.parse().expect("KITTIES_FUZZ_SEED must be a number")),
+		Err(_) => (0..RUNS).for_each(run),
+	}
+}
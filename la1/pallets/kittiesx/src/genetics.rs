@@ -0,0 +1,154 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_io::hashing::blake2_256;
+use sp_runtime::{Perbill, RuntimeDebug};
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+// DNA layout: bytes [0, 8) hold four genes as allele pairs (color, pattern, eyes, fur),
+// bytes [8, 16) are free bits that are mixed from both parents on breed.
+pub const GENE_COUNT: usize = 4;
+const COLOR_GENE: usize = 0;
+const PATTERN_GENE: usize = 1;
+const EYES_GENE: usize = 2;
+const FUR_GENE: usize = 3;
+
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Color {
+	Black,
+	White,
+	Orange,
+	Grey,
+	Cream,
+	Cinnamon,
+}
+
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Pattern {
+	Solid,
+	Tabby,
+	Bicolor,
+	Tortoiseshell,
+	Calico,
+}
+
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Eyes {
+	Brown,
+	Green,
+	Blue,
+	Odd,
+}
+
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Fur {
+	Short,
+	Long,
+	Curly,
+	Hairless,
+}
+
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Rarity {
+	Common,
+	Uncommon,
+	Rare,
+	Epic,
+	Legendary,
+}
+
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct KittyTraits {
+	pub color: Color,
+	pub pattern: Pattern,
+	pub eyes: Eyes,
+	pub fur: Fur,
+	pub rarity: Rarity,
+}
+
+// Variants are ordered by dominance: the lower index wins, so a higher variant
+// is only expressed when both alleles carry it.
+const VARIANTS: [u8; GENE_COUNT] = [6, 5, 4, 4];
+
+fn expressed(dna: &[u8; 16], gene: usize) -> u8 {
+	let variants = VARIANTS[gene];
+	let a = dna[gene * 2] % variants;
+	let b = dna[gene * 2 + 1] % variants;
+	a.min(b)
+}
+
+pub fn decode(dna: &[u8; 16]) -> KittyTraits {
+	let color = match expressed(dna, COLOR_GENE) {
+		0 => Color::Black,
+		1 => Color::White,
+		2 => Color::Orange,
+		3 => Color::Grey,
+		4 => Color::Cream,
+		_ => Color::Cinnamon,
+	};
+	let pattern = match expressed(dna, PATTERN_GENE) {
+		0 => Pattern::Solid,
+		1 => Pattern::Tabby,
+		2 => Pattern::Bicolor,
+		3 => Pattern::Tortoiseshell,
+		_ => Pattern::Calico,
+	};
+	let eyes = match expressed(dna, EYES_GENE) {
+		0 => Eyes::Brown,
+		1 => Eyes::Green,
+		2 => Eyes::Blue,
+		_ => Eyes::Odd,
+	};
+	let fur = match expressed(dna, FUR_GENE) {
+		0 => Fur::Short,
+		1 => Fur::Long,
+		2 => Fur::Curly,
+		_ => Fur::Hairless,
+	};
+
+	// rarity score: how many recessive steps are expressed over all genes, max 15
+	let score: u8 = (0..GENE_COUNT).map(|gene| expressed(dna, gene)).sum();
+	let rarity = match score {
+		0..=3 => Rarity::Common,
+		4..=6 => Rarity::Uncommon,
+		7..=9 => Rarity::Rare,
+		10..=12 => Rarity::Epic,
+		_ => Rarity::Legendary,
+	};
+
+	KittyTraits { color, pattern, eyes, fur, rarity }
+}
+
+/// Builds a child DNA: every gene takes one allele from each parent, chosen by `seed`,
+/// then each allele mutates to a random value with probability `mutation_rate`.
+pub fn inherit(
+	dna_1: &[u8; 16],
+	dna_2: &[u8; 16],
+	seed: &[u8; 16],
+	mutation_rate: Perbill,
+) -> [u8; 16] {
+	let mut data = [0u8; 16];
+	for gene in 0..GENE_COUNT {
+		data[gene * 2] = dna_1[gene * 2 + (seed[gene * 2] & 1) as usize];
+		data[gene * 2 + 1] = dna_2[gene * 2 + (seed[gene * 2 + 1] & 1) as usize];
+	}
+	for (i, byte) in data.iter_mut().enumerate().skip(GENE_COUNT * 2) {
+		*byte = (dna_1[i] & seed[i]) | (dna_2[i] & !seed[i]);
+	}
+
+	let entropy = blake2_256(seed);
+	let threshold = mutation_rate.mul_floor(u16::MAX as u32 + 1);
+	for allele in 0..GENE_COUNT * 2 {
+		let roll = u16::from_le_bytes([entropy[allele * 2], entropy[allele * 2 + 1]]) as u32;
+		if roll < threshold {
+			data[allele] = entropy[16 + allele];
+		}
+	}
+	data
+}
@@ -0,0 +1,113 @@
+//! Index of kitties by owner, trait and sale price, kept in persistent offchain storage by the
+//! offchain worker and served by the node's `kittiesIndex_*` RPC.
+//!
+//! Offchain storage has no prefix iteration, so every lookup is a single key holding the full
+//! list of matching kitties. Each kitty also keeps the record it was last indexed with, which
+//! is what gets removed from the lists when the kitty changes.
+//!
+//! Offchain storage is not forked with the chain either. A block's events are only applied on
+//! top of the index built for its parent; after a reorg or skipped blocks every kitty is
+//! indexed again against the current state.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	genetics::{Color, Eyes, Fur, KittyTraits, Pattern, Rarity},
+	CollectionKittyId,
+};
+
+const INDEX_PREFIX: &[u8] = b"kittiesx::index::";
+/// Kitties on sale with their price, cheapest first.
+pub const ON_SALE_KEY: &[u8] = b"kittiesx::index::on_sale";
+/// Every kitty holding an `IndexedKitty` record, so a full resync can drop the ones the chain
+/// no longer has.
+pub const KITTIES_KEY: &[u8] = b"kittiesx::index::kitties";
+/// Number and hash of the block the index was last brought up to date with.
+pub const LAST_INDEXED_KEY: &[u8] = b"kittiesx::index::last_indexed";
+/// Held while a worker updates the index, as workers of consecutive blocks can overlap.
+pub const LOCK_KEY: &[u8] = b"kittiesx::index::lock";
+
+/// A trait value kitties can be looked up by.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum TraitFilter {
+	Color(Color),
+	Pattern(Pattern),
+	Eyes(Eyes),
+	Fur(Fur),
+	Rarity(Rarity),
+}
+
+impl TraitFilter {
+	pub fn all(traits: &KittyTraits) -> [TraitFilter; 5] {
+		[
+			TraitFilter::Color(traits.color),
+			TraitFilter::Pattern(traits.pattern),
+			TraitFilter::Eyes(traits.eyes),
+			TraitFilter::Fur(traits.fur),
+			TraitFilter::Rarity(traits.rarity),
+		]
+	}
+}
+
+/// What the index last recorded for a kitty.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct IndexedKitty<AccountId, Balance> {
+	pub owner: AccountId,
+	pub traits: KittyTraits,
+	pub price: Option<Balance>,
+}
+
+/// Key of the `Vec<CollectionKittyId>` held by `owner`.
+pub fn owner_key<AccountId: Encode>(owner: &AccountId) -> Vec<u8> {
+	key(b"owner::", owner)
+}
+
+/// Key of the `Vec<CollectionKittyId>` showing `filter`.
+pub fn trait_key(filter: &TraitFilter) -> Vec<u8> {
+	key(b"trait::", filter)
+}
+
+/// Key of the `IndexedKitty` last recorded for `kitty_id`.
+pub fn kitty_key(kitty_id: &CollectionKittyId) -> Vec<u8> {
+	key(b"kitty::", kitty_id)
+}
+
+fn key(kind: &[u8], item: &impl Encode) -> Vec<u8> {
+	[INDEX_PREFIX, kind, &item.encode()].concat()
+}
+
+pub fn insert_id(ids: &mut Vec<CollectionKittyId>, kitty_id: CollectionKittyId) {
+	if let Err(pos) = ids.binary_search(&kitty_id) {
+		ids.insert(pos, kitty_id);
+	}
+}
+
+pub fn remove_id(ids: &mut Vec<CollectionKittyId>, kitty_id: CollectionKittyId) {
+	if let Ok(pos) = ids.binary_search(&kitty_id) {
+		ids.remove(pos);
+	}
+}
+
+pub fn insert_on_sale<Balance: Ord + Copy>(
+	on_sale: &mut Vec<(CollectionKittyId, Balance)>,
+	kitty_id: CollectionKittyId,
+	price: Balance,
+) {
+	let pos = on_sale.partition_point(|(id, p)| (*p, *id) < (price, kitty_id));
+	on_sale.insert(pos, (kitty_id, price));
+}
+
+pub fn remove_on_sale<Balance>(
+	on_sale: &mut Vec<(CollectionKittyId, Balance)>,
+	kitty_id: CollectionKittyId,
+) {
+	on_sale.retain(|(id, _)| *id != kitty_id);
+}
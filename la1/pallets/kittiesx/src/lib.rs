@@ -3,7 +3,9 @@
 
 pub use pallet::*;
 
-mod migrations;
+pub mod genetics;
+pub mod index;
+pub mod migrations;
 
 #[cfg(test)]
 mod mock;
@@ -14,36 +16,54 @@ mod tests;
 #[cfg(test)]
 mod tests2;
 
+#[cfg(test)]
+mod fuzz;
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod weights;
+pub use genetics::KittyTraits;
 pub use weights::*;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
+	use crate::genetics::{self, KittyTraits};
 	use frame_support::{
 		dispatch::Vec,
 		pallet,
 		pallet_prelude::{OptionQuery, StorageValue, ValueQuery, *},
-		traits::{Currency, ExistenceRequirement, Randomness, ReservableCurrency, StorageVersion},
+		traits::{
+			BalanceStatus, Currency, ExistenceRequirement, Randomness, ReservableCurrency,
+			StorageVersion,
+		},
 		Blake2_128Concat, PalletId,
 	};
 	use frame_system::{
 		pallet,
 		pallet_prelude::{BlockNumberFor, *},
 	};
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
 	use sp_io::hashing::blake2_128;
 	use sp_runtime::{
 		offchain::{
 			http,
 			storage::{StorageRetrievalError, StorageValueRef},
+			storage_lock::{StorageLock, Time},
 			Duration,
 		},
-		traits::{AccountIdConversion, Zero},
+		traits::{AccountIdConversion, One, Saturating, Zero},
+		Perbill,
 	};
 
+	pub type CollectionId = u32;
 	pub type KittyId = u32;
+	/// Kitties are numbered per collection, so a kitty is identified by both ids.
+	pub type CollectionKittyId = (CollectionId, KittyId);
+	pub type CollectionMetadata<T> = BoundedVec<u8, <T as Config>::MaxMetadataLength>;
+	pub type KittyName = BoundedVec<u8, ConstU32<8>>;
+	pub type KittyFeature = BoundedVec<u8, ConstU32<5>>;
 
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -51,15 +71,113 @@ pub mod pallet {
 	#[derive(
 		Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen,
 	)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	pub struct Kitty {
 		pub dna: [u8; 16],
 		pub name: [u8; 8],
 		pub feature: [u8; 5],
 	}
 
+	/// A kitty together with who owns it and what it is on sale for, as served to clients. A
+	/// listed kitty is shown with its seller, not the escrow account holding it.
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct KittyInfo<AccountId, Balance> {
+		pub kitty: Kitty,
+		pub owner: AccountId,
+		pub price: Option<Balance>,
+	}
+
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct Collection<AccountId, Balance, Metadata> {
+		pub owner: AccountId,
+		pub metadata: Metadata,
+		pub max_supply: Option<u32>,
+		pub mint_price: Balance,
+	}
+
+	pub type CollectionOf<T> =
+		Collection<<T as frame_system::Config>::AccountId, BalanceOf<T>, CollectionMetadata<T>>;
+
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct Auction<AccountId, Balance, BlockNumber> {
+		pub owner: AccountId,
+		pub reserve_price: Balance,
+		pub end: BlockNumber,
+		pub best_bid: Option<(AccountId, Balance)>,
+	}
+
+	pub type AuctionOf<T> =
+		Auction<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct PendingMint<AccountId, Balance, BlockNumber> {
+		pub owner: AccountId,
+		pub name: [u8; 8],
+		pub price: Balance,
+		pub committed_at: BlockNumber,
+	}
+
+	/// A kitty lent to `borrower` until block `expires_at`, when it returns to its owner.
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct Lease<AccountId, BlockNumber> {
+		pub borrower: AccountId,
+		pub expires_at: BlockNumber,
+	}
+
+	pub type LeaseOf<T> = Lease<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	/// Who minted a kitty, and the share of every later sale they are paid.
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct CreatorInfo<AccountId> {
+		pub creator: AccountId,
+		pub royalty: Perbill,
+	}
+
+	pub type PendingMintOf<T> =
+		PendingMint<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+	/// The part of a lazy migration that runs next, see [`migrations::lazy`].
+	#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub enum MigrationStage<BlockNumber> {
+		/// Rewriting `Kitties` into the current layout, still under their plain `KittyId` keys.
+		Layout,
+		/// Removing the `KittyId` entries of the indexes that `Rekey` builds again.
+		ClearIndexes { started_at: BlockNumber },
+		/// Moving each kitty's entries into the default collection.
+		Rekey { started_at: BlockNumber },
+		/// Running the auction, mint and lease queues of the blocks skipped while re-keying.
+		CatchUp { next: BlockNumber, until: BlockNumber },
+	}
+
+	/// Progress of a lazy migration; `cursor` is the last kitty already migrated.
+	#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct MigrationState<BlockNumber> {
+		pub from_version: u16,
+		pub cursor: Option<KittyId>,
+		pub migrated: u32,
+		pub stage: MigrationStage<BlockNumber>,
+	}
+
+	pub type MigrationStateOf<T> = MigrationState<BlockNumberFor<T>>;
+
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+	pub struct KittyLineage {
+		pub kitty_id: CollectionKittyId,
+		pub generation: u32,
+		pub parents: Option<(CollectionKittyId, CollectionKittyId)>,
+		pub depth: u32,
+		pub burned: bool,
+	}
+
+	pub const MAX_ANCESTRY_DEPTH: u32 = 8;
+	/// Most kitties returned by one page of a listing query.
+	pub const MAX_PAGE_SIZE: u32 = 100;
+	/// Collection that kitties minted before collections existed were moved into.
+	pub const DEFAULT_COLLECTION_ID: CollectionId = 0;
 	pub const DEFAULT_KITTY_FEATURE: [u8; 5] = *b"happy";
 	pub const DEFAULT_KITTY_NAME: [u8; 8] = *b"mimimimi";
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -67,47 +185,372 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type RuntimeEvent: From<Event<Self>>
+			+ TryInto<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type WeightInfo: WeightInfo;
 
+		/// Entropy for kitty DNA. `random` must report the block since which its output is
+		/// known, so a VRF-backed source (e.g. BABE) makes committed mints unpredictable.
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
 		type Currency: ReservableCurrency<Self::AccountId>;
-		#[pallet::constant]
-		type KittyCreatePrice: Get<BalanceOf<Self>>;
 		type PalletId: Get<PalletId>;
+		#[pallet::constant]
+		type MaxKittiesOwned: Get<u32>;
+		#[pallet::constant]
+		type MarketplaceFee: Get<Perbill>;
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
+		#[pallet::constant]
+		type MutationRate: Get<Perbill>;
+		#[pallet::constant]
+		type MetadataDeposit: Get<BalanceOf<Self>>;
+		#[pallet::constant]
+		type BreedCooldown: Get<BlockNumberFor<Self>>;
+		#[pallet::constant]
+		type BurnRefundRatio: Get<Perbill>;
+		/// Blocks between `commit_create` and the block whose randomness reveals the DNA.
+		#[pallet::constant]
+		type RevealDelay: Get<BlockNumberFor<Self>>;
+		#[pallet::constant]
+		type MaxMintsPerBlock: Get<u32>;
+		/// Kitties rewritten per block while a lazy migration is running.
+		#[pallet::constant]
+		type MigrationBatchSize: Get<u32>;
+		/// Maximum length of a collection metadata URI.
+		#[pallet::constant]
+		type MaxMetadataLength: Get<u32>;
+		/// Kitties minted or transferred by a single batch call.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+		/// Highest royalty a creator may ask for on sales of their kitties.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Perbill>;
+		#[pallet::constant]
+		type MaxLeasesPerBlock: Get<u32>;
 	}
 
+	#[pallet::storage]
+	#[pallet::getter(fn next_collection_id)]
+	pub type NextCollectionId<T> = StorageValue<_, CollectionId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collections)]
+	pub type Collections<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, CollectionOf<T>, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn next_kitty_id)]
-	pub type NextKittyId<T> = StorageValue<_, KittyId, ValueQuery>;
+	pub type NextKittyId<T> = StorageMap<_, Blake2_128Concat, CollectionId, KittyId, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn kitties)]
-	pub type Kitties<T> = StorageMap<_, Blake2_128Concat, KittyId, Kitty>;
+	pub type Kitties<T> = StorageMap<_, Blake2_128Concat, CollectionKittyId, Kitty>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn kitty_owners)]
-	pub type KittyOwners<T: Config> = StorageMap<_, Blake2_128Concat, KittyId, T::AccountId>;
+	pub type KittyOwners<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionKittyId, T::AccountId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn owned_kitties)]
+	pub type OwnedKitties<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<CollectionKittyId, T::MaxKittiesOwned>,
+		ValueQuery,
+	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn kitty_parents)]
-	pub type KittyParents<T: Config> =
-		StorageMap<_, Blake2_128Concat, KittyId, (KittyId, KittyId), OptionQuery>;
+	pub type KittyParents<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CollectionKittyId,
+		(CollectionKittyId, CollectionKittyId),
+		OptionQuery,
+	>;
+
+	// kitties minted by `create`, or bred before generations were tracked, are generation 0
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_generations)]
+	pub type KittyGenerations<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionKittyId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_last_bred)]
+	pub type KittyLastBred<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionKittyId, BlockNumberFor<T>, OptionQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn kitty_on_sale)]
 	pub type KittyOnSale<T: Config> =
-		StorageMap<_, Blake2_128Concat, KittyId, BalanceOf<T>, OptionQuery>;
+		StorageMap<_, Blake2_128Concat, CollectionKittyId, BalanceOf<T>, OptionQuery>;
+
+	// a listed kitty is held by its escrow account, this is who gets it back or gets paid for it
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_sellers)]
+	pub type KittySellers<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionKittyId, T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_auctions)]
+	pub type KittyAuctions<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionKittyId, AuctionOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn auctions_ending)]
+	pub type AuctionsEnding<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<CollectionKittyId, T::MaxAuctionsPerBlock>,
+		ValueQuery,
+	>;
+
+	// a lent kitty keeps its owner in `KittyOwners`, the borrower only gets to breed with it
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_leases)]
+	pub type KittyLeases<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionKittyId, LeaseOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn leases_expiring)]
+	pub type LeasesExpiring<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<CollectionKittyId, T::MaxLeasesPerBlock>,
+		ValueQuery,
+	>;
+
+	// what was paid for each kitty, the base for its burn refund
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_mint_prices)]
+	pub type KittyMintPrices<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionKittyId, BalanceOf<T>, OptionQuery>;
+
+	// kitties minted before royalties existed have no creator recorded and pay none
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_creators)]
+	pub type KittyCreators<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionKittyId, CreatorInfo<T::AccountId>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn metadata_deposits)]
+	pub type MetadataDeposits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CollectionKittyId,
+		(T::AccountId, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_approvals)]
+	pub type KittyApprovals<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionKittyId, T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn operator_approvals)]
+	pub type OperatorApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_mints)]
+	pub type PendingMints<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionKittyId, PendingMintOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn mints_revealing)]
+	pub type MintsRevealing<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<CollectionKittyId, T::MaxMintsPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn ongoing_migration)]
+	pub type OngoingMigration<T: Config> = StorageValue<_, MigrationStateOf<T>, OptionQuery>;
+
+	/// Kitties minted at genesis as `(owner, dna, name)`. They go into the default collection,
+	/// which is created with `mint_price` and numbered in list order.
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub mint_price: BalanceOf<T>,
+		pub kitties: Vec<(T::AccountId, [u8; 16], [u8; 8])>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let collection = Collection {
+				owner: Pallet::<T>::get_pallet_account_id(),
+				metadata: Default::default(),
+				max_supply: None,
+				mint_price: self.mint_price,
+			};
+			Collections::<T>::insert(DEFAULT_COLLECTION_ID, collection);
+			NextCollectionId::<T>::put(DEFAULT_COLLECTION_ID + 1);
+
+			for (index, (owner, dna, name)) in self.kitties.iter().enumerate() {
+				let kitty_id = (DEFAULT_COLLECTION_ID, index as KittyId);
+				let kitty = Kitty { dna: *dna, name: *name, feature: DEFAULT_KITTY_FEATURE };
+				Kitties::<T>::insert(kitty_id, kitty);
+				KittyOwners::<T>::insert(kitty_id, owner);
+				Pallet::<T>::add_owned_kitty(owner, kitty_id)
+					.expect("genesis kitties exceed MaxKittiesOwned for one owner");
+			}
+			NextKittyId::<T>::insert(DEFAULT_COLLECTION_ID, self.kitties.len() as KittyId);
+		}
+	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		KittyCreated { owner: T::AccountId, kitty_id: KittyId, kitty: Kitty },
-		KittyBred { owner: T::AccountId, kitty_id: KittyId, kitty: Kitty },
-		KittyTransfer { owner: T::AccountId, recipient: T::AccountId, kitty_id: KittyId },
-		KittyOnSale { owner: T::AccountId, kitty_id: KittyId, price: BalanceOf<T> },
-		KittyBought { owner: T::AccountId, kitty_id: KittyId },
+		KittyCreated {
+			owner: T::AccountId,
+			kitty_id: CollectionKittyId,
+			kitty: Kitty,
+		},
+		KittyBred {
+			owner: T::AccountId,
+			kitty_id: CollectionKittyId,
+			kitty: Kitty,
+		},
+		KittyTransfer {
+			owner: T::AccountId,
+			recipient: T::AccountId,
+			kitty_id: CollectionKittyId,
+		},
+		KittyOnSale {
+			owner: T::AccountId,
+			kitty_id: CollectionKittyId,
+			price: BalanceOf<T>,
+		},
+		KittyBought {
+			owner: T::AccountId,
+			seller: T::AccountId,
+			kitty_id: CollectionKittyId,
+			price: BalanceOf<T>,
+			fee: BalanceOf<T>,
+			creator: Option<T::AccountId>,
+			royalty: BalanceOf<T>,
+		},
+		KittySaleCancelled {
+			owner: T::AccountId,
+			kitty_id: CollectionKittyId,
+		},
+		KittyPriceUpdated {
+			owner: T::AccountId,
+			kitty_id: CollectionKittyId,
+			price: BalanceOf<T>,
+		},
+		AuctionCreated {
+			owner: T::AccountId,
+			kitty_id: CollectionKittyId,
+			reserve_price: BalanceOf<T>,
+			end: BlockNumberFor<T>,
+		},
+		AuctionBid {
+			bidder: T::AccountId,
+			kitty_id: CollectionKittyId,
+			amount: BalanceOf<T>,
+		},
+		AuctionSettled {
+			owner: T::AccountId,
+			winner: T::AccountId,
+			kitty_id: CollectionKittyId,
+			price: BalanceOf<T>,
+		},
+		AuctionUnsold {
+			owner: T::AccountId,
+			kitty_id: CollectionKittyId,
+		},
+		KittyNameSet {
+			owner: T::AccountId,
+			kitty_id: CollectionKittyId,
+			name: [u8; 8],
+		},
+		KittyFeatureSet {
+			owner: T::AccountId,
+			kitty_id: CollectionKittyId,
+			feature: [u8; 5],
+		},
+		KittyBurned {
+			owner: T::AccountId,
+			kitty_id: CollectionKittyId,
+			refund: BalanceOf<T>,
+		},
+		KittyApproval {
+			owner: T::AccountId,
+			spender: T::AccountId,
+			kitty_id: CollectionKittyId,
+		},
+		KittyApprovalCancelled {
+			owner: T::AccountId,
+			spender: T::AccountId,
+			kitty_id: CollectionKittyId,
+		},
+		KittyApprovalForAll {
+			owner: T::AccountId,
+			operator: T::AccountId,
+			approved: bool,
+		},
+		KittyMintCommitted {
+			owner: T::AccountId,
+			kitty_id: CollectionKittyId,
+			reveal_at: BlockNumberFor<T>,
+		},
+		KittyMintCancelled {
+			owner: T::AccountId,
+			kitty_id: CollectionKittyId,
+		},
+		MigrationProgressed {
+			migrated: u32,
+		},
+		MigrationCompleted {
+			migrated: u32,
+		},
+		CollectionCreated {
+			owner: T::AccountId,
+			collection_id: CollectionId,
+			max_supply: Option<u32>,
+			mint_price: BalanceOf<T>,
+		},
+		CollectionMetadataSet {
+			owner: T::AccountId,
+			collection_id: CollectionId,
+			metadata: CollectionMetadata<T>,
+		},
+		CollectionMintPriceSet {
+			owner: T::AccountId,
+			collection_id: CollectionId,
+			mint_price: BalanceOf<T>,
+		},
+		KittyLent {
+			owner: T::AccountId,
+			borrower: T::AccountId,
+			kitty_id: CollectionKittyId,
+			expires_at: BlockNumberFor<T>,
+		},
+		KittyReturned {
+			owner: T::AccountId,
+			borrower: T::AccountId,
+			kitty_id: CollectionKittyId,
+		},
 	}
 
 	#[pallet::error]
@@ -119,24 +562,60 @@ pub mod pallet {
 		ErrprRecipient,
 		KittyOnSale,
 		NotEnoughCurrency,
+		TooManyOwned,
+		KittyNotOnSale,
+		BuyOwnKitty,
+		PriceTooHigh,
+		KittyInAuction,
+		AuctionNotFound,
+		AuctionEnded,
+		InvalidAuctionEnd,
+		TooManyAuctions,
+		BidOwnAuction,
+		BidTooLow,
+		InvalidKittyName,
+		InvalidKittyFeature,
+		KittyInCooldown,
+		KittyRelated,
+		NotApproved,
+		ApproveSelf,
+		TooManyMints,
+		MigrationOngoing,
+		InvalidCollectionId,
+		NotCollectionOwner,
+		MaxSupplyReached,
+		EmptyBatch,
+		BatchTooLarge,
+		RoyaltyTooHigh,
+		KittyLeased,
+		KittyNotLeased,
+		NotKittyBorrower,
+		NotKittyUser,
+		InvalidLeaseDuration,
+		TooManyLeases,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_runtime_upgrade() -> Weight {
-			let mut _w = migrations::v1::upgrade::<T>();
-			_w = migrations::v2::upgrade::<T>();
-			_w
-		}
-
 		fn offchain_worker(n: BlockNumberFor<T>) {
 			log::info!("=== offchain_worker === {:?}", n);
-			//Self::offchain_storage(n);
+			Self::update_index(n);
 		}
 
 		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
 			log::info!("=== on_initialize === {:?}", n);
-			Weight::from_parts(0, 0)
+			let migration = match OngoingMigration::<T>::get() {
+				Some(state) => migrations::lazy::step::<T>(state, n),
+				None => Weight::zero(),
+			};
+			// the queues name kitties that are being re-keyed, skipped blocks are caught up later
+			if Self::queues_paused() {
+				return T::DbWeight::get().reads(2).saturating_add(migration)
+			}
+			T::DbWeight::get()
+				.reads(2)
+				.saturating_add(migration)
+				.saturating_add(Self::run_queues(n, n))
 		}
 
 		fn on_finalize(n: BlockNumberFor<T>) {
@@ -152,101 +631,108 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::do_something())]
-		pub fn create(origin: OriginFor<T>) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::create())]
+		pub fn create(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			royalty: Perbill,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let kitty_id = Self::get_next_id()?;
-			let kitty = Kitty {
-				dna: Self::random_value(&who),
-				name: DEFAULT_KITTY_NAME,
-				feature: DEFAULT_KITTY_FEATURE,
-			};
-
-			let create_price = T::KittyCreatePrice::get();
-			ensure!(T::Currency::can_reserve(&who, create_price), Error::<T>::NotEnoughCurrency);
-			T::Currency::transfer(
-				&who,
-				&Self::get_pallet_account_id(),
-				create_price,
-				ExistenceRequirement::KeepAlive,
-			)?;
-
-			Kitties::<T>::insert(kitty_id, kitty);
-			KittyOwners::<T>::insert(kitty_id, &who);
-
-			Self::deposit_event(Event::KittyCreated { owner: who, kitty_id, kitty });
-			Ok(())
+			Self::do_create(who, collection_id, DEFAULT_KITTY_NAME, royalty)
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::do_something())]
+		#[pallet::weight(T::WeightInfo::breed())]
 		pub fn breed(
 			origin: OriginFor<T>,
-			kitty_id_1: KittyId,
-			kitty_id_2: KittyId,
+			collection_id: CollectionId,
+			kitty_id_1: CollectionKittyId,
+			kitty_id_2: CollectionKittyId,
+			royalty: Perbill,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
 
 			ensure!(kitty_id_1 != kitty_id_2, Error::<T>::KittySingleParent);
 			ensure!(Kitties::<T>::contains_key(kitty_id_1), Error::<T>::InvalidKittyId);
 			ensure!(Kitties::<T>::contains_key(kitty_id_2), Error::<T>::InvalidKittyId);
+			ensure!(!Self::is_related(kitty_id_1, kitty_id_2), Error::<T>::KittyRelated);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			for id in [kitty_id_1, kitty_id_2] {
+				ensure!(Self::kitty_user(id).as_ref() == Some(&who), Error::<T>::NotKittyUser);
+				if let Some(last_bred) = Self::kitty_last_bred(id) {
+					ensure!(
+						now >= last_bred.saturating_add(T::BreedCooldown::get()),
+						Error::<T>::KittyInCooldown
+					);
+				}
+			}
 
-			let kitty_id = Self::get_next_id()?;
+			let kitty_id = Self::get_next_id(collection_id)?;
+			let price = Self::charge_mint_price(&who, collection_id)?;
 
 			let kitty_p_1 = Self::kitties(kitty_id_1).unwrap();
 			let kitty_p_2 = Self::kitties(kitty_id_2).unwrap();
-			let selector = Self::random_value(&who);
-			let mut data = [0u8; 16];
-			for i in 0..selector.len() {
-				data[i] = (kitty_p_1.dna[i] & selector[i]) | (kitty_p_2.dna[i] & !selector[i]);
-			}
+			let selector = Self::random_value(&who, kitty_id);
+			let data = genetics::inherit(
+				&kitty_p_1.dna,
+				&kitty_p_2.dna,
+				&selector,
+				T::MutationRate::get(),
+			);
 			let kitty =
 				Kitty { dna: data, name: DEFAULT_KITTY_NAME, feature: DEFAULT_KITTY_FEATURE };
 
 			Kitties::<T>::insert(kitty_id, kitty);
 			KittyOwners::<T>::insert(kitty_id, &who);
+			Self::add_owned_kitty(&who, kitty_id)?;
 			KittyParents::<T>::insert(kitty_id, (kitty_id_1, kitty_id_2));
+			Self::record_mint_price(kitty_id, price);
+			Self::record_creator(kitty_id, &who, royalty)?;
+			let generation = Self::kitty_generations(kitty_id_1)
+				.max(Self::kitty_generations(kitty_id_2))
+				.saturating_add(1);
+			KittyGenerations::<T>::insert(kitty_id, generation);
+			KittyLastBred::<T>::insert(kitty_id_1, now);
+			KittyLastBred::<T>::insert(kitty_id_2, now);
 
 			Self::deposit_event(Event::KittyBred { owner: who, kitty_id, kitty });
 			Ok(())
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::do_something())]
+		#[pallet::weight(T::WeightInfo::transfer())]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			recipient: T::AccountId,
-			kitty_id: KittyId,
+			kitty_id: CollectionKittyId,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(who != recipient, Error::<T>::ErrprRecipient);
-			ensure!(Kitties::<T>::contains_key(kitty_id), Error::<T>::InvalidKittyId);
-			ensure!(!KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::KittyOnSale);
-
-			let owner = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
-			ensure!(who == owner, Error::<T>::ErrorKittyOwner);
-
-			KittyOwners::<T>::insert(kitty_id, &recipient);
 
-			Self::deposit_event(Event::KittyTransfer { owner, recipient, kitty_id });
-			Ok(())
+			Self::do_transfer(who, recipient, kitty_id)
 		}
 
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::do_something())]
+		#[pallet::weight(T::WeightInfo::sale())]
 		pub fn sale(
 			origin: OriginFor<T>,
-			kitty_id: KittyId,
+			kitty_id: CollectionKittyId,
 			price: BalanceOf<T>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
 
 			ensure!(Kitties::<T>::contains_key(kitty_id), Error::<T>::InvalidKittyId);
 			let owner = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
 			ensure!(who == owner, Error::<T>::ErrorKittyOwner);
 			ensure!(!KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::KittyOnSale);
+			ensure!(!KittyAuctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+			ensure!(!KittyLeases::<T>::contains_key(kitty_id), Error::<T>::KittyLeased);
 
+			Self::transfer_ownership(&owner, &Self::escrow_account(kitty_id), kitty_id)?;
+			KittySellers::<T>::insert(kitty_id, &owner);
 			KittyOnSale::<T>::insert(kitty_id, price);
 
 			Self::deposit_event(Event::KittyOnSale { owner, kitty_id, price });
@@ -254,103 +740,1139 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::do_something())]
-		pub fn buy(origin: OriginFor<T>, kitty_id: KittyId) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::buy())]
+		pub fn buy(
+			origin: OriginFor<T>,
+			kitty_id: CollectionKittyId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
 
-			let kitty_on_sale = Self::kitty_on_sale(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
-			let owner = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			let kitty_on_sale = Self::kitty_on_sale(kitty_id).ok_or(Error::<T>::KittyNotOnSale)?;
+			let holder = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			let owner = Self::kitty_sellers(kitty_id).unwrap_or_else(|| holder.clone());
+			ensure!(who != owner, Error::<T>::BuyOwnKitty);
 
 			let price = kitty_on_sale;
+			ensure!(price <= max_price, Error::<T>::PriceTooHigh);
 			ensure!(T::Currency::can_reserve(&who, price), Error::<T>::NotEnoughCurrency);
 
-			KittyOwners::<T>::insert(kitty_id, &who);
+			Self::transfer_ownership(&holder, &who, kitty_id)?;
 			KittyOnSale::<T>::remove(kitty_id);
+			KittySellers::<T>::remove(kitty_id);
+
+			let fee = T::MarketplaceFee::get().mul_floor(price);
+			if !fee.is_zero() {
+				T::Currency::transfer(
+					&who,
+					&Self::get_pallet_account_id(),
+					fee,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			}
+			let proceeds = price.saturating_sub(fee);
+			let (creator, royalty) = match Self::royalty_due(kitty_id, &owner, price, proceeds) {
+				Some((creator, royalty)) => {
+					T::Currency::transfer(
+						&who,
+						&creator,
+						royalty,
+						ExistenceRequirement::KeepAlive,
+					)?;
+					(Some(creator), royalty)
+				}
+				None => (None, Zero::zero()),
+			};
+			T::Currency::transfer(
+				&who,
+				&owner,
+				proceeds.saturating_sub(royalty),
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			Self::deposit_event(Event::KittyBought {
+				owner: who,
+				seller: owner,
+				kitty_id,
+				price,
+				fee,
+				creator,
+				royalty,
+			});
+			Ok(())
+		}
 
-			T::Currency::transfer(&who, &owner, price, ExistenceRequirement::KeepAlive)?;
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::cancel_sale())]
+		pub fn cancel_sale(origin: OriginFor<T>, kitty_id: CollectionKittyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			ensure!(KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::KittyNotOnSale);
+			let holder = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			let owner = Self::kitty_sellers(kitty_id).unwrap_or_else(|| holder.clone());
+			ensure!(who == owner, Error::<T>::ErrorKittyOwner);
+
+			if holder != owner {
+				Self::transfer_ownership(&holder, &owner, kitty_id)?;
+			}
+			KittyOnSale::<T>::remove(kitty_id);
+			KittySellers::<T>::remove(kitty_id);
 
-			Self::deposit_event(Event::KittyBought { owner: who, kitty_id });
+			Self::deposit_event(Event::KittySaleCancelled { owner, kitty_id });
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		fn get_next_id() -> Result<KittyId, DispatchError> {
-			NextKittyId::<T>::try_mutate(|next_id| -> Result<KittyId, DispatchError> {
-				let current_id = *next_id;
-				*next_id = next_id.checked_add(1).ok_or(Error::<T>::KittyOverflow)?;
-				Ok(current_id)
-			})
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::update_price())]
+		pub fn update_price(
+			origin: OriginFor<T>,
+			kitty_id: CollectionKittyId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			ensure!(KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::KittyNotOnSale);
+			let owner = Self::seller_or_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(who == owner, Error::<T>::ErrorKittyOwner);
+
+			KittyOnSale::<T>::insert(kitty_id, price);
+
+			Self::deposit_event(Event::KittyPriceUpdated { owner, kitty_id, price });
+			Ok(())
 		}
 
-		fn random_value(sender: &T::AccountId) -> [u8; 16] {
-			//<pallet_insecure_randomness_collective_flip::Pallet<T>>::random_seed(),
-			let payload = (
-				T::Randomness::random_seed(),
-				&sender,
-				<frame_system::Pallet<T>>::extrinsic_index(),
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			kitty_id: CollectionKittyId,
+			reserve_price: BalanceOf<T>,
+			end: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			ensure!(Kitties::<T>::contains_key(kitty_id), Error::<T>::InvalidKittyId);
+			let owner = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(who == owner, Error::<T>::ErrorKittyOwner);
+			ensure!(!KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::KittyOnSale);
+			ensure!(!KittyAuctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+			ensure!(!KittyLeases::<T>::contains_key(kitty_id), Error::<T>::KittyLeased);
+			ensure!(end > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidAuctionEnd);
+
+			AuctionsEnding::<T>::try_mutate(end, |ending| {
+				ending.try_push(kitty_id).map_err(|_| Error::<T>::TooManyAuctions)
+			})?;
+			KittyAuctions::<T>::insert(
+				kitty_id,
+				Auction { owner: owner.clone(), reserve_price, end, best_bid: None },
 			);
-			payload.using_encoded(blake2_128)
+
+			Self::deposit_event(Event::AuctionCreated { owner, kitty_id, reserve_price, end });
+			Ok(())
 		}
 
-		fn get_pallet_account_id() -> T::AccountId {
-			T::PalletId::get().into_account_truncating()
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::bid())]
+		pub fn bid(
+			origin: OriginFor<T>,
+			kitty_id: CollectionKittyId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			let mut auction = Self::kitty_auctions(kitty_id).ok_or(Error::<T>::AuctionNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < auction.end,
+				Error::<T>::AuctionEnded
+			);
+			ensure!(who != auction.owner, Error::<T>::BidOwnAuction);
+			ensure!(amount >= auction.reserve_price, Error::<T>::BidTooLow);
+			if let Some((_, best)) = &auction.best_bid {
+				ensure!(amount > *best, Error::<T>::BidTooLow);
+			}
+
+			if let Some((bidder, best)) = auction.best_bid.take() {
+				T::Currency::unreserve(&bidder, best);
+			}
+			T::Currency::reserve(&who, amount).map_err(|_| Error::<T>::NotEnoughCurrency)?;
+			auction.best_bid = Some((who.clone(), amount));
+			KittyAuctions::<T>::insert(kitty_id, auction);
+
+			Self::deposit_event(Event::AuctionBid { bidder: who, kitty_id, amount });
+			Ok(())
 		}
 
-		#[allow(dead_code)]
-		fn time_sleep(n: BlockNumberFor<T>) {
-			// case for hook offchain_worker
-			log::info!("=== time_sleep start === {:?}", n);
-			let timeout = sp_io::offchain::timestamp()
-				.add(sp_runtime::offchain::Duration::from_millis(100000));
-			sp_io::offchain::sleep_until(timeout);
-			log::info!("=== time_sleep end === {:?}", n);
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::create_with_name())]
+		pub fn create_with_name(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			name: KittyName,
+			royalty: Perbill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let name = Self::validate_text(&name).ok_or(Error::<T>::InvalidKittyName)?;
+			Self::do_create(who, collection_id, name, royalty)
 		}
 
-		#[allow(dead_code)]
-		fn offchain_storage(n: BlockNumberFor<T>) {
-			// case for hook offchain_worker
-			log::info!("=== offchain_storage start === {:?}", n);
-			if n % 2u32.into() != Zero::zero() {
-				let key = Self::derive_block_number_to_key(n);
-				let val_ref = StorageValueRef::persistent(&key);
-
-				let random_slice = sp_io::offchain::random_seed();
-				let timestamp = sp_io::offchain::timestamp().unix_millis();
-				let v = (random_slice, timestamp);
-
-				// // case by set
-				// val_ref.set(&v);
-				// log::info!("=== offchain_storage set === {:?}", v.0);
-
-				// case by mutate
-				let res =
-					val_ref.mutate(|val: Result<Option<([u8; 32], u64)>, _>| -> Result<_, u8> {
-						match val {
-							Ok(Some(_)) => Ok(v),
-							_ => Ok(v),
-						}
-					});
-				match res {
-					Ok(val) => log::info!("=== offchain_storage mutate === {:?}", val),
-					_ => (),
-				}
-			} else {
-				let key = Self::derive_block_number_to_key(n - 1u32.into());
-				let mut val_ref = StorageValueRef::persistent(&key);
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_name())]
+		pub fn set_name(
+			origin: OriginFor<T>,
+			kitty_id: CollectionKittyId,
+			name: KittyName,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
 
-				if let Ok(Some((_s, t))) = val_ref.get::<([u8; 32], u64)>() {
-					log::info!("=== offchain_storage get === {:?}", t);
-					val_ref.clear();
-				}
-			}
-			log::info!("=== offchain_storage end === {:?}", n);
+			let name = Self::validate_text(&name).ok_or(Error::<T>::InvalidKittyName)?;
+			let mut kitty = Self::kitties(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			let owner = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(who == owner, Error::<T>::ErrorKittyOwner);
+
+			Self::hold_metadata_deposit(&owner, kitty_id)?;
+			kitty.name = name;
+			Kitties::<T>::insert(kitty_id, kitty);
+
+			Self::deposit_event(Event::KittyNameSet { owner, kitty_id, name });
+			Ok(())
 		}
 
-		fn derive_block_number_to_key(n: BlockNumberFor<T>) -> Vec<u8> {
-			n.using_encoded(|encode| {
-				b"storage::".iter().chain(encode).copied().collect::<Vec<u8>>()
-			})
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_feature())]
+		pub fn set_feature(
+			origin: OriginFor<T>,
+			kitty_id: CollectionKittyId,
+			feature: KittyFeature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			let feature = Self::validate_text(&feature).ok_or(Error::<T>::InvalidKittyFeature)?;
+			let mut kitty = Self::kitties(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			let owner = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(who == owner, Error::<T>::ErrorKittyOwner);
+
+			Self::hold_metadata_deposit(&owner, kitty_id)?;
+			kitty.feature = feature;
+			Kitties::<T>::insert(kitty_id, kitty);
+
+			Self::deposit_event(Event::KittyFeatureSet { owner, kitty_id, feature });
+			Ok(())
+		}
+
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(origin: OriginFor<T>, kitty_id: CollectionKittyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			ensure!(Kitties::<T>::contains_key(kitty_id), Error::<T>::InvalidKittyId);
+			let owner = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(who == owner, Error::<T>::ErrorKittyOwner);
+			ensure!(!KittyAuctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+			ensure!(!KittyLeases::<T>::contains_key(kitty_id), Error::<T>::KittyLeased);
+
+			Kitties::<T>::remove(kitty_id);
+			KittyOwners::<T>::remove(kitty_id);
+			KittyOnSale::<T>::remove(kitty_id);
+			KittyLastBred::<T>::remove(kitty_id);
+			KittyApprovals::<T>::remove(kitty_id);
+			KittyCreators::<T>::remove(kitty_id);
+			Self::remove_owned_kitty(&owner, kitty_id);
+			if let Some((depositor, deposit)) = MetadataDeposits::<T>::take(kitty_id) {
+				T::Currency::unreserve(&depositor, deposit);
+			}
+
+			let paid = KittyMintPrices::<T>::take(kitty_id).unwrap_or_else(Zero::zero);
+			let refund = T::BurnRefundRatio::get().mul_floor(paid);
+			if !refund.is_zero() {
+				T::Currency::transfer(
+					&Self::get_pallet_account_id(),
+					&owner,
+					refund,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			}
+
+			Self::deposit_event(Event::KittyBurned { owner, kitty_id, refund });
+			Ok(())
+		}
+
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			kitty_id: CollectionKittyId,
+			spender: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			let owner = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(
+				who == owner || Self::operator_approvals(&owner, &who),
+				Error::<T>::ErrorKittyOwner
+			);
+			ensure!(spender != owner, Error::<T>::ApproveSelf);
+
+			KittyApprovals::<T>::insert(kitty_id, &spender);
+
+			Self::deposit_event(Event::KittyApproval { owner, spender, kitty_id });
+			Ok(())
+		}
+
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::set_approval_for_all())]
+		pub fn set_approval_for_all(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			approved: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who != operator, Error::<T>::ApproveSelf);
+
+			if approved {
+				OperatorApprovals::<T>::insert(&who, &operator, true);
+			} else {
+				OperatorApprovals::<T>::remove(&who, &operator);
+			}
+
+			Self::deposit_event(Event::KittyApprovalForAll { owner: who, operator, approved });
+			Ok(())
+		}
+
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::transfer_from())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			recipient: T::AccountId,
+			kitty_id: CollectionKittyId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(from != recipient, Error::<T>::ErrprRecipient);
+			ensure!(Kitties::<T>::contains_key(kitty_id), Error::<T>::InvalidKittyId);
+			ensure!(!KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::KittyOnSale);
+			ensure!(!KittyAuctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+			ensure!(!KittyLeases::<T>::contains_key(kitty_id), Error::<T>::KittyLeased);
+
+			let owner = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(from == owner, Error::<T>::ErrorKittyOwner);
+			ensure!(Self::is_approved_or_owner(&who, &owner, kitty_id), Error::<T>::NotApproved);
+
+			Self::transfer_ownership(&owner, &recipient, kitty_id)?;
+
+			Self::deposit_event(Event::KittyTransfer { owner, recipient, kitty_id });
+			Ok(())
+		}
+
+		/// Pays for a kitty now and mints it `RevealDelay` blocks later, with DNA drawn from
+		/// randomness that was not known when the commitment was submitted.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::commit_create())]
+		pub fn commit_create(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			royalty: Perbill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			let kitty_id = Self::get_next_id(collection_id)?;
+			let price = Self::charge_mint_price(&who, collection_id)?;
+			// recorded now so the reveal only has to mint, dropped again if the mint is cancelled
+			Self::record_creator(kitty_id, &who, royalty)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let reveal_at = now.saturating_add(T::RevealDelay::get().max(One::one()));
+			MintsRevealing::<T>::try_mutate(reveal_at, |revealing| {
+				revealing.try_push(kitty_id).map_err(|_| Error::<T>::TooManyMints)
+			})?;
+			PendingMints::<T>::insert(
+				kitty_id,
+				PendingMint {
+					owner: who.clone(),
+					name: DEFAULT_KITTY_NAME,
+					price,
+					committed_at: now,
+				},
+			);
+
+			Self::deposit_event(Event::KittyMintCommitted { owner: who, kitty_id, reveal_at });
+			Ok(())
+		}
+
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::create_collection())]
+		pub fn create_collection(
+			origin: OriginFor<T>,
+			metadata: CollectionMetadata<T>,
+			max_supply: Option<u32>,
+			mint_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let collection_id = NextCollectionId::<T>::try_mutate(
+				|next_id| -> Result<CollectionId, DispatchError> {
+					let current_id = *next_id;
+					*next_id = next_id.checked_add(1).ok_or(Error::<T>::KittyOverflow)?;
+					Ok(current_id)
+				},
+			)?;
+			Collections::<T>::insert(
+				collection_id,
+				Collection { owner: who.clone(), metadata, max_supply, mint_price },
+			);
+
+			Self::deposit_event(Event::CollectionCreated {
+				owner: who,
+				collection_id,
+				max_supply,
+				mint_price,
+			});
+			Ok(())
+		}
+
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_collection_metadata())]
+		pub fn set_collection_metadata(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			metadata: CollectionMetadata<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Collections::<T>::try_mutate(collection_id, |collection| -> DispatchResult {
+				let collection = collection.as_mut().ok_or(Error::<T>::InvalidCollectionId)?;
+				ensure!(collection.owner == who, Error::<T>::NotCollectionOwner);
+				collection.metadata = metadata.clone();
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CollectionMetadataSet {
+				owner: who,
+				collection_id,
+				metadata,
+			});
+			Ok(())
+		}
+
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::set_mint_price())]
+		pub fn set_mint_price(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			mint_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Collections::<T>::try_mutate(collection_id, |collection| -> DispatchResult {
+				let collection = collection.as_mut().ok_or(Error::<T>::InvalidCollectionId)?;
+				ensure!(collection.owner == who, Error::<T>::NotCollectionOwner);
+				collection.mint_price = mint_price;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CollectionMintPriceSet {
+				owner: who,
+				collection_id,
+				mint_price,
+			});
+			Ok(())
+		}
+
+		/// Mints `count` kitties into a collection, charging its mint price for each one.
+		/// Fails as a whole if any of them cannot be minted.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::create_batch(*count))]
+		pub fn create_batch(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			count: u32,
+			royalty: Perbill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(count > 0, Error::<T>::EmptyBatch);
+			ensure!(count <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+			for _ in 0..count {
+				Self::do_create(who.clone(), collection_id, DEFAULT_KITTY_NAME, royalty)?;
+			}
+			Ok(())
+		}
+
+		/// Transfers each kitty to its recipient. Fails as a whole if any transfer fails.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::transfer_batch(transfers.len() as u32))]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			transfers: BoundedVec<(T::AccountId, CollectionKittyId), T::MaxBatchSize>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!transfers.is_empty(), Error::<T>::EmptyBatch);
+
+			for (recipient, kitty_id) in transfers {
+				Self::do_transfer(who.clone(), recipient, kitty_id)?;
+			}
+			Ok(())
+		}
+
+		/// Lends a kitty to `borrower` for `duration` blocks. The borrower may breed with it but
+		/// not transfer or sell it, and it returns to its owner once the lease expires.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::lend())]
+		pub fn lend(
+			origin: OriginFor<T>,
+			kitty_id: CollectionKittyId,
+			borrower: T::AccountId,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			let owner = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(who == owner, Error::<T>::ErrorKittyOwner);
+			ensure!(borrower != owner, Error::<T>::ErrprRecipient);
+			ensure!(!duration.is_zero(), Error::<T>::InvalidLeaseDuration);
+			ensure!(!KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::KittyOnSale);
+			ensure!(!KittyAuctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+			ensure!(!KittyLeases::<T>::contains_key(kitty_id), Error::<T>::KittyLeased);
+
+			let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			LeasesExpiring::<T>::try_mutate(expires_at, |expiring| {
+				expiring.try_push(kitty_id).map_err(|_| Error::<T>::TooManyLeases)
+			})?;
+			KittyLeases::<T>::insert(kitty_id, Lease { borrower: borrower.clone(), expires_at });
+
+			Self::deposit_event(Event::KittyLent { owner, borrower, kitty_id, expires_at });
+			Ok(())
+		}
+
+		/// Hands a borrowed kitty back to its owner before the lease expires.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::return_kitty())]
+		pub fn return_kitty(origin: OriginFor<T>, kitty_id: CollectionKittyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			let lease = Self::kitty_leases(kitty_id).ok_or(Error::<T>::KittyNotLeased)?;
+			ensure!(who == lease.borrower, Error::<T>::NotKittyBorrower);
+
+			LeasesExpiring::<T>::mutate(lease.expires_at, |expiring| {
+				expiring.retain(|id| *id != kitty_id)
+			});
+			Self::end_lease(kitty_id);
+			Ok(())
+		}
+
+		/// Takes back the approval `approve` gave for a kitty, before it changes hands.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::cancel_approval())]
+		pub fn cancel_approval(
+			origin: OriginFor<T>,
+			kitty_id: CollectionKittyId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			let owner = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(
+				who == owner || Self::operator_approvals(&owner, &who),
+				Error::<T>::ErrorKittyOwner
+			);
+			let spender = KittyApprovals::<T>::take(kitty_id).ok_or(Error::<T>::NotApproved)?;
+
+			Self::deposit_event(Event::KittyApprovalCancelled { owner, spender, kitty_id });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		pub fn kitty_traits(kitty_id: CollectionKittyId) -> Option<KittyTraits> {
+			Self::kitties(kitty_id).map(|kitty| genetics::decode(&kitty.dna))
+		}
+
+		pub fn kitty_info(
+			kitty_id: CollectionKittyId,
+		) -> Option<KittyInfo<T::AccountId, BalanceOf<T>>> {
+			let kitty = Self::kitties(kitty_id)?;
+			let owner = Self::seller_or_owner(kitty_id)?;
+			Some(KittyInfo { kitty, owner, price: Self::kitty_on_sale(kitty_id) })
+		}
+
+		/// Up to `limit` kitties of `owner`, skipping the first `start` in the order acquired.
+		pub fn kitties_by_owner(
+			owner: &T::AccountId,
+			start: u32,
+			limit: u32,
+		) -> Vec<CollectionKittyId> {
+			Self::owned_kitties(owner)
+				.into_iter()
+				.skip(start as usize)
+				.take(limit.min(MAX_PAGE_SIZE) as usize)
+				.collect()
+		}
+
+		/// Up to `limit` kitties on sale with their price, continuing after `start_after`.
+		/// Pages follow storage order, so the last kitty of a page is the cursor for the next.
+		pub fn kitties_on_sale(
+			start_after: Option<CollectionKittyId>,
+			limit: u32,
+		) -> Vec<(CollectionKittyId, BalanceOf<T>)> {
+			let on_sale = match start_after {
+				Some(kitty_id) => {
+					KittyOnSale::<T>::iter_from(KittyOnSale::<T>::hashed_key_for(kitty_id))
+				}
+				None => KittyOnSale::<T>::iter(),
+			};
+			on_sale.take(limit.min(MAX_PAGE_SIZE) as usize).collect()
+		}
+
+		/// Who may breed with a kitty: its borrower while it is lent out, otherwise its owner.
+		pub fn kitty_user(kitty_id: CollectionKittyId) -> Option<T::AccountId> {
+			match Self::kitty_leases(kitty_id) {
+				Some(lease) => Some(lease.borrower),
+				None => Self::kitty_owners(kitty_id),
+			}
+		}
+
+		/// Ancestors of a kitty in breadth-first order, starting with the kitty itself at depth 0.
+		pub fn kitty_ancestry(kitty_id: CollectionKittyId, depth: u32) -> Vec<KittyLineage> {
+			let depth = depth.min(MAX_ANCESTRY_DEPTH);
+			let mut lineage = Vec::new();
+			if !Kitties::<T>::contains_key(kitty_id) {
+				return lineage
+			}
+			// burned ancestors keep their parents and generation, so the tree stays complete
+
+			let mut queue = sp_std::collections::vec_deque::VecDeque::from([(kitty_id, 0u32)]);
+			while let Some((id, level)) = queue.pop_front() {
+				if lineage.iter().any(|node: &KittyLineage| node.kitty_id == id) {
+					continue;
+				}
+				let parents = Self::kitty_parents(id);
+				if let Some((parent_1, parent_2)) = parents {
+					if level < depth {
+						queue.push_back((parent_1, level + 1));
+						queue.push_back((parent_2, level + 1));
+					}
+				}
+				lineage.push(KittyLineage {
+					kitty_id: id,
+					generation: Self::kitty_generations(id),
+					parents,
+					depth: level,
+					burned: !Kitties::<T>::contains_key(id),
+				});
+			}
+			lineage
+		}
+
+		/// Parent and child, or siblings sharing at least one parent.
+		fn is_related(kitty_id_1: CollectionKittyId, kitty_id_2: CollectionKittyId) -> bool {
+			let parents_1 = Self::kitty_parents(kitty_id_1);
+			let parents_2 = Self::kitty_parents(kitty_id_2);
+			if let Some((a, b)) = parents_1 {
+				if a == kitty_id_2 || b == kitty_id_2 {
+					return true
+				}
+			}
+			if let Some((a, b)) = parents_2 {
+				if a == kitty_id_1 || b == kitty_id_1 {
+					return true
+				}
+			}
+			match (parents_1, parents_2) {
+				(Some((a1, b1)), Some((a2, b2))) => a1 == a2 || a1 == b2 || b1 == a2 || b1 == b2,
+				_ => false,
+			}
+		}
+
+		fn do_create(
+			who: T::AccountId,
+			collection_id: CollectionId,
+			name: [u8; 8],
+			royalty: Perbill,
+		) -> DispatchResult {
+			Self::ensure_not_migrating()?;
+			let kitty_id = Self::get_next_id(collection_id)?;
+			let kitty = Kitty {
+				dna: Self::random_value(&who, kitty_id),
+				name,
+				feature: DEFAULT_KITTY_FEATURE,
+			};
+
+			let price = Self::charge_mint_price(&who, collection_id)?;
+
+			Kitties::<T>::insert(kitty_id, kitty);
+			KittyOwners::<T>::insert(kitty_id, &who);
+			Self::add_owned_kitty(&who, kitty_id)?;
+			Self::record_mint_price(kitty_id, price);
+			Self::record_creator(kitty_id, &who, royalty)?;
+
+			Self::deposit_event(Event::KittyCreated { owner: who, kitty_id, kitty });
+			Ok(())
+		}
+
+		fn do_transfer(
+			who: T::AccountId,
+			recipient: T::AccountId,
+			kitty_id: CollectionKittyId,
+		) -> DispatchResult {
+			Self::ensure_not_migrating()?;
+			ensure!(who != recipient, Error::<T>::ErrprRecipient);
+			ensure!(Kitties::<T>::contains_key(kitty_id), Error::<T>::InvalidKittyId);
+			ensure!(!KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::KittyOnSale);
+			ensure!(!KittyAuctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+			ensure!(!KittyLeases::<T>::contains_key(kitty_id), Error::<T>::KittyLeased);
+
+			let owner = Self::kitty_owners(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(who == owner, Error::<T>::ErrorKittyOwner);
+
+			Self::transfer_ownership(&owner, &recipient, kitty_id)?;
+
+			Self::deposit_event(Event::KittyTransfer { owner, recipient, kitty_id });
+			Ok(())
+		}
+
+		/// Kitty calls wait until a lazy migration has rewritten the storage they touch. Catching
+		/// up on skipped queues only settles what was already due, so calls go ahead meanwhile.
+		fn ensure_not_migrating() -> DispatchResult {
+			let migrating = Self::ongoing_migration()
+				.map_or(false, |state| !matches!(state.stage, MigrationStage::CatchUp { .. }));
+			ensure!(!migrating, Error::<T>::MigrationOngoing);
+			Ok(())
+		}
+
+		fn queues_paused() -> bool {
+			Self::ongoing_migration().map_or(false, |state| {
+				matches!(
+					state.stage,
+					MigrationStage::ClearIndexes { .. } | MigrationStage::Rekey { .. }
+				)
+			})
+		}
+
+		/// Settles the auctions, reveals the mints and ends the leases queued for block `due`.
+		pub(crate) fn run_queues(due: BlockNumberFor<T>, now: BlockNumberFor<T>) -> Weight {
+			let ending = AuctionsEnding::<T>::take(due);
+			for kitty_id in ending.iter() {
+				Self::settle_auction(*kitty_id);
+			}
+			let revealing = MintsRevealing::<T>::take(due);
+			for kitty_id in revealing.iter() {
+				Self::reveal_mint(*kitty_id, now);
+			}
+			let expiring = LeasesExpiring::<T>::take(due);
+			for kitty_id in expiring.iter() {
+				Self::end_lease(*kitty_id);
+			}
+			T::DbWeight::get()
+				.reads_writes(3, 3)
+				.saturating_add(T::WeightInfo::settle_auction().saturating_mul(ending.len() as u64))
+				.saturating_add(T::WeightInfo::reveal_mint().saturating_mul(revealing.len() as u64))
+				.saturating_add(T::WeightInfo::end_lease().saturating_mul(expiring.len() as u64))
+		}
+
+		/// Moves the collection's mint price into the pallet account and returns it.
+		fn charge_mint_price(
+			who: &T::AccountId,
+			collection_id: CollectionId,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::InvalidCollectionId)?;
+			let price = collection.mint_price;
+			ensure!(T::Currency::can_reserve(who, price), Error::<T>::NotEnoughCurrency);
+			T::Currency::transfer(
+				who,
+				&Self::get_pallet_account_id(),
+				price,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			Ok(price)
+		}
+
+		fn record_creator(
+			kitty_id: CollectionKittyId,
+			creator: &T::AccountId,
+			royalty: Perbill,
+		) -> DispatchResult {
+			ensure!(royalty <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+			KittyCreators::<T>::insert(kitty_id, CreatorInfo { creator: creator.clone(), royalty });
+			Ok(())
+		}
+
+		/// The creator's cut of a sale by `seller`, taken from `proceeds`. Nothing is owed when
+		/// creators sell their own kitty, or when the cut could not open the creator's account.
+		fn royalty_due(
+			kitty_id: CollectionKittyId,
+			seller: &T::AccountId,
+			price: BalanceOf<T>,
+			proceeds: BalanceOf<T>,
+		) -> Option<(T::AccountId, BalanceOf<T>)> {
+			let info = Self::kitty_creators(kitty_id)?;
+			if &info.creator == seller {
+				return None
+			}
+			let royalty = info.royalty.mul_floor(price).min(proceeds);
+			let balance = T::Currency::total_balance(&info.creator).saturating_add(royalty);
+			if royalty.is_zero() || balance < T::Currency::minimum_balance() {
+				return None
+			}
+			Some((info.creator, royalty))
+		}
+
+		fn record_mint_price(kitty_id: CollectionKittyId, price: BalanceOf<T>) {
+			if !price.is_zero() {
+				KittyMintPrices::<T>::insert(kitty_id, price);
+			}
+		}
+
+		/// Accepts non-empty UTF-8 without control characters and pads it with spaces.
+		fn validate_text<const N: usize>(input: &[u8]) -> Option<[u8; N]> {
+			let text = sp_std::str::from_utf8(input).ok()?;
+			if text.is_empty() || input.len() > N || text.chars().any(|c| c.is_control()) {
+				return None
+			}
+			let mut padded = [b' '; N];
+			padded[..input.len()].copy_from_slice(input);
+			Some(padded)
+		}
+
+		/// The metadata deposit is held once per kitty by the owner who renamed it, and released
+		/// again when the kitty changes hands.
+		fn hold_metadata_deposit(
+			owner: &T::AccountId,
+			kitty_id: CollectionKittyId,
+		) -> DispatchResult {
+			if let Some((depositor, deposit)) = Self::metadata_deposits(kitty_id) {
+				if depositor == *owner {
+					return Ok(())
+				}
+				T::Currency::unreserve(&depositor, deposit);
+			}
+
+			let deposit = T::MetadataDeposit::get();
+			if deposit.is_zero() {
+				MetadataDeposits::<T>::remove(kitty_id);
+				return Ok(())
+			}
+			T::Currency::reserve(owner, deposit).map_err(|_| Error::<T>::NotEnoughCurrency)?;
+			MetadataDeposits::<T>::insert(kitty_id, (owner.clone(), deposit));
+			Ok(())
+		}
+
+		fn get_next_id(collection_id: CollectionId) -> Result<CollectionKittyId, DispatchError> {
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::InvalidCollectionId)?;
+			NextKittyId::<T>::try_mutate(
+				collection_id,
+				|next_id| -> Result<CollectionKittyId, DispatchError> {
+					let current_id = *next_id;
+					if let Some(max_supply) = collection.max_supply {
+						ensure!(current_id < max_supply, Error::<T>::MaxSupplyReached);
+					}
+					*next_id = next_id.checked_add(1).ok_or(Error::<T>::KittyOverflow)?;
+					Ok((collection_id, current_id))
+				},
+			)
+		}
+
+		/// The kitty id keeps kitties minted by one extrinsic, like a batch, from sharing DNA.
+		fn random_value(sender: &T::AccountId, kitty_id: CollectionKittyId) -> [u8; 16] {
+			//<pallet_insecure_randomness_collective_flip::Pallet<T>>::random_seed(),
+			let (random, _) = T::Randomness::random(b"kittiesx/dna");
+			let payload = (random, &sender, <frame_system::Pallet<T>>::extrinsic_index(), kitty_id);
+			payload.using_encoded(blake2_128)
+		}
+
+		fn get_pallet_account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Who a kitty belongs to for its clients: the seller while it is held in escrow, otherwise
+		/// its owner. Listings made before escrow existed have no seller and stay with the owner.
+		pub fn seller_or_owner(kitty_id: CollectionKittyId) -> Option<T::AccountId> {
+			Self::kitty_sellers(kitty_id).or_else(|| Self::kitty_owners(kitty_id))
+		}
+
+		/// The account a listed kitty is held by until it is bought or its sale is cancelled.
+		/// Each listing gets its own, so no account runs into `MaxKittiesOwned`.
+		pub fn escrow_account(kitty_id: CollectionKittyId) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(kitty_id)
+		}
+
+		pub(crate) fn settle_auction(kitty_id: CollectionKittyId) {
+			let Some(auction) = KittyAuctions::<T>::take(kitty_id) else { return };
+			let owner = auction.owner;
+
+			if let Some((winner, price)) = auction.best_bid {
+				if Self::transfer_ownership(&owner, &winner, kitty_id).is_ok() {
+					if let Err(e) = T::Currency::repatriate_reserved(
+						&winner,
+						&owner,
+						price,
+						BalanceStatus::Free,
+					) {
+						log::warn!("=== settle_auction === {:?} {:?}", kitty_id, e);
+					}
+
+					Self::deposit_event(Event::AuctionSettled { owner, winner, kitty_id, price });
+					return
+				}
+				T::Currency::unreserve(&winner, price);
+			}
+
+			Self::deposit_event(Event::AuctionUnsold { owner, kitty_id });
+		}
+
+		pub(crate) fn reveal_mint(kitty_id: CollectionKittyId, now: BlockNumberFor<T>) {
+			let Some(mint) = PendingMints::<T>::take(kitty_id) else { return };
+
+			let (random, known_since) =
+				T::Randomness::random(&(b"kittiesx/mint", kitty_id).encode());
+			if known_since <= mint.committed_at {
+				// the source still serves randomness that existed at commit time, wait for fresh
+				let next = now.saturating_add(One::one());
+				if MintsRevealing::<T>::try_mutate(next, |revealing| revealing.try_push(kitty_id))
+					.is_ok()
+				{
+					PendingMints::<T>::insert(kitty_id, mint);
+					return
+				}
+				return Self::cancel_mint(kitty_id, mint.owner, mint.price)
+			}
+
+			if Self::add_owned_kitty(&mint.owner, kitty_id).is_err() {
+				return Self::cancel_mint(kitty_id, mint.owner, mint.price)
+			}
+			let dna = (random, &mint.owner, kitty_id).using_encoded(blake2_128);
+			let kitty = Kitty { dna, name: mint.name, feature: DEFAULT_KITTY_FEATURE };
+			Kitties::<T>::insert(kitty_id, kitty);
+			KittyOwners::<T>::insert(kitty_id, &mint.owner);
+			Self::record_mint_price(kitty_id, mint.price);
+
+			Self::deposit_event(Event::KittyCreated { owner: mint.owner, kitty_id, kitty });
+		}
+
+		pub(crate) fn end_lease(kitty_id: CollectionKittyId) {
+			let Some(lease) = KittyLeases::<T>::take(kitty_id) else { return };
+			// lent kitties cannot change owner or be burned, so the owner is still there
+			if let Some(owner) = Self::kitty_owners(kitty_id) {
+				Self::deposit_event(Event::KittyReturned {
+					owner,
+					borrower: lease.borrower,
+					kitty_id,
+				});
+			}
+		}
+
+		pub(crate) fn cancel_mint(
+			kitty_id: CollectionKittyId,
+			owner: T::AccountId,
+			price: BalanceOf<T>,
+		) {
+			if let Err(e) = T::Currency::transfer(
+				&Self::get_pallet_account_id(),
+				&owner,
+				price,
+				ExistenceRequirement::AllowDeath,
+			) {
+				log::warn!("=== cancel_mint === {:?} {:?}", kitty_id, e);
+			}
+			KittyCreators::<T>::remove(kitty_id);
+			Self::deposit_event(Event::KittyMintCancelled { owner, kitty_id });
+		}
+
+		/// Moves a kitty between accounts, keeping the ownership index in sync, clearing any
+		/// single-kitty approval and releasing the previous owner's metadata deposit.
+		fn transfer_ownership(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			kitty_id: CollectionKittyId,
+		) -> DispatchResult {
+			Self::add_owned_kitty(to, kitty_id)?;
+			Self::remove_owned_kitty(from, kitty_id);
+			KittyOwners::<T>::insert(kitty_id, to);
+			KittyApprovals::<T>::remove(kitty_id);
+			if let Some((depositor, deposit)) = MetadataDeposits::<T>::take(kitty_id) {
+				T::Currency::unreserve(&depositor, deposit);
+			}
+			Ok(())
+		}
+
+		fn is_approved_or_owner(
+			who: &T::AccountId,
+			owner: &T::AccountId,
+			kitty_id: CollectionKittyId,
+		) -> bool {
+			who == owner
+				|| Self::kitty_approvals(kitty_id).as_ref() == Some(who)
+				|| Self::operator_approvals(owner, who)
+		}
+
+		fn add_owned_kitty(owner: &T::AccountId, kitty_id: CollectionKittyId) -> DispatchResult {
+			OwnedKitties::<T>::try_mutate(owner, |owned| {
+				owned.try_push(kitty_id).map_err(|_| Error::<T>::TooManyOwned.into())
+			})
+		}
+
+		fn remove_owned_kitty(owner: &T::AccountId, kitty_id: CollectionKittyId) {
+			OwnedKitties::<T>::mutate_exists(owner, |owned| {
+				if let Some(ids) = owned {
+					ids.retain(|id| *id != kitty_id);
+					if ids.is_empty() {
+						*owned = None;
+					}
+				}
+			});
+		}
+
+		#[allow(dead_code)]
+		fn time_sleep(n: BlockNumberFor<T>) {
+			// case for hook offchain_worker
+			log::info!("=== time_sleep start === {:?}", n);
+			let timeout = sp_io::offchain::timestamp()
+				.add(sp_runtime::offchain::Duration::from_millis(100000));
+			sp_io::offchain::sleep_until(timeout);
+			log::info!("=== time_sleep end === {:?}", n);
+		}
+
+		/// Re-indexes the kitties touched by this block's events. Every kitty is indexed again
+		/// when the index was not built up to the parent block: on the first run, after skipped
+		/// blocks, or after a reorg replaced the blocks it was built from.
+		fn update_index(n: BlockNumberFor<T>) {
+			let mut lock = StorageLock::<Time>::new(index::LOCK_KEY);
+			let _guard = lock.lock();
+
+			let last_indexed = StorageValueRef::persistent(index::LAST_INDEXED_KEY);
+			let parent = (n.saturating_sub(One::one()), frame_system::Pallet::<T>::parent_hash());
+			let last = last_indexed.get::<(BlockNumberFor<T>, T::Hash)>().ok().flatten();
+			let kitty_ids: Vec<CollectionKittyId> = if last == Some(parent) {
+				frame_system::Pallet::<T>::read_events_no_consensus()
+					.filter_map(|record| {
+						let event = <T as Config>::RuntimeEvent::from(record.event);
+						event.try_into().ok().and_then(|event| Self::indexed_kitty_id(&event))
+					})
+					.collect()
+			} else {
+				// kitties indexed on blocks the chain no longer has are cleared as well
+				let mut kitty_ids: Vec<CollectionKittyId> =
+					StorageValueRef::persistent(index::KITTIES_KEY)
+						.get()
+						.ok()
+						.flatten()
+						.unwrap_or_default();
+				for kitty_id in Kitties::<T>::iter_keys() {
+					index::insert_id(&mut kitty_ids, kitty_id);
+				}
+				kitty_ids
+			};
+			for kitty_id in kitty_ids {
+				Self::index_kitty(kitty_id);
+			}
+			// the executive records the hash of the block the workers run for
+			last_indexed.set(&(n, frame_system::Pallet::<T>::block_hash(n)));
+		}
+
+		/// The kitty whose owner, traits or sale price the event may have changed.
+		fn indexed_kitty_id(event: &Event<T>) -> Option<CollectionKittyId> {
+			match event {
+				Event::KittyCreated { kitty_id, .. }
+				| Event::KittyBred { kitty_id, .. }
+				| Event::KittyTransfer { kitty_id, .. }
+				| Event::KittyOnSale { kitty_id, .. }
+				| Event::KittyBought { kitty_id, .. }
+				| Event::KittySaleCancelled { kitty_id, .. }
+				| Event::KittyPriceUpdated { kitty_id, .. }
+				| Event::AuctionSettled { kitty_id, .. }
+				| Event::KittyBurned { kitty_id, .. } => Some(*kitty_id),
+				_ => None,
+			}
+		}
+
+		fn index_kitty(kitty_id: CollectionKittyId) {
+			let key = index::kitty_key(&kitty_id);
+			let mut record_ref = StorageValueRef::persistent(&key);
+			let old =
+				record_ref.get::<index::IndexedKitty<T::AccountId, BalanceOf<T>>>().ok().flatten();
+			let new = Self::seller_or_owner(kitty_id).zip(Self::kitties(kitty_id)).map(
+				|(owner, kitty)| index::IndexedKitty {
+					owner,
+					traits: genetics::decode(&kitty.dna),
+					price: Self::kitty_on_sale(kitty_id),
+				},
+			);
+			if old == new {
+				return
+			}
+
+			let old_owner = old.as_ref().map(|record| &record.owner);
+			let new_owner = new.as_ref().map(|record| &record.owner);
+			if old_owner != new_owner {
+				if let Some(owner) = old_owner {
+					Self::mutate_index(&index::owner_key(owner), |ids| {
+						index::remove_id(ids, kitty_id)
+					});
+				}
+				if let Some(owner) = new_owner {
+					Self::mutate_index(&index::owner_key(owner), |ids| {
+						index::insert_id(ids, kitty_id)
+					});
+				}
+			}
+
+			let old_traits = old.as_ref().map(|record| record.traits);
+			let new_traits = new.as_ref().map(|record| record.traits);
+			if old_traits != new_traits {
+				for filter in old_traits.iter().flat_map(index::TraitFilter::all) {
+					Self::mutate_index(&index::trait_key(&filter), |ids| {
+						index::remove_id(ids, kitty_id)
+					});
+				}
+				for filter in new_traits.iter().flat_map(index::TraitFilter::all) {
+					Self::mutate_index(&index::trait_key(&filter), |ids| {
+						index::insert_id(ids, kitty_id)
+					});
+				}
+			}
+
+			let old_price = old.as_ref().and_then(|record| record.price);
+			let new_price = new.as_ref().and_then(|record| record.price);
+			if old_price != new_price {
+				Self::mutate_index(index::ON_SALE_KEY, |on_sale| {
+					index::remove_on_sale(on_sale, kitty_id);
+					if let Some(price) = new_price {
+						index::insert_on_sale(on_sale, kitty_id, price);
+					}
+				});
+			}
+
+			if old.is_some() != new.is_some() {
+				Self::mutate_index(index::KITTIES_KEY, |ids| match new {
+					Some(_) => index::insert_id(ids, kitty_id),
+					None => index::remove_id(ids, kitty_id),
+				});
+			}
+			match new {
+				Some(record) => record_ref.set(&record),
+				None => record_ref.clear(),
+			}
+		}
+
+		fn mutate_index<V: Encode + Decode + Default>(key: &[u8], f: impl FnOnce(&mut V)) {
+			let value_ref = StorageValueRef::persistent(key);
+			let mut value = value_ref.get::<V>().ok().flatten().unwrap_or_default();
+			f(&mut value);
+			value_ref.set(&value);
 		}
 
 		#[allow(dead_code)]
@@ -0,0 +1,257 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	storage::{unhashed, StoragePrefixedMap},
+	traits::Get,
+	weights::Weight,
+	Blake2_128Concat, StorageHasher,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{One, Saturating};
+
+use super::{v1::KittyV0, v2::KittyV1, v3, v4::PendingMintV3};
+use crate::{
+	AuctionOf, AuctionsEnding, BalanceOf, Collections, Config, Event, Kitties, Kitty,
+	KittyApprovals, KittyAuctions, KittyGenerations, KittyId, KittyLastBred, KittyMintPrices,
+	KittyOnSale, KittyOwners, KittyParents, MetadataDeposits, MigrationStage, MigrationStateOf,
+	MintsRevealing, NextKittyId, OngoingMigration, OwnedKitties, Pallet, PendingMint, PendingMints,
+	DEFAULT_COLLECTION_ID,
+};
+
+/// Runs one block's share of the ongoing migration: a batch of kitties while rewriting or
+/// re-keying them, a batch of index entries while clearing, and one skipped block of queues
+/// while catching up. Clears the migration once its last stage is done.
+pub fn step<T: Config>(mut state: MigrationStateOf<T>, now: BlockNumberFor<T>) -> Weight {
+	let (weight, finished) = match state.stage {
+		MigrationStage::Layout => rewrite_layout::<T>(&mut state),
+		MigrationStage::ClearIndexes { started_at } => clear_indexes::<T>(&mut state, started_at),
+		MigrationStage::Rekey { started_at } => rekey::<T>(&mut state, started_at, now),
+		MigrationStage::CatchUp { next, until } => catch_up::<T>(&mut state, next, until, now),
+	};
+
+	if finished {
+		OngoingMigration::<T>::kill();
+		log::info!("=== lazy migration === done, {} kitties", state.migrated);
+		Pallet::<T>::deposit_event(Event::MigrationCompleted { migrated: state.migrated });
+	} else {
+		OngoingMigration::<T>::put(&state);
+		Pallet::<T>::deposit_event(Event::MigrationProgressed { migrated: state.migrated });
+	}
+
+	weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+}
+
+/// Rewrites up to `MigrationBatchSize` kitties into the current layout, resuming after the
+/// cursor left by the previous block.
+fn rewrite_layout<T: Config>(state: &mut MigrationStateOf<T>) -> (Weight, bool) {
+	let mut keys = match state.cursor {
+		Some(last) => v3::Kitties::<T>::iter_keys_from(v3::Kitties::<T>::hashed_key_for(last)),
+		None => v3::Kitties::<T>::iter_keys(),
+	};
+
+	let batch = T::MigrationBatchSize::get();
+	let mut processed = 0u32;
+	let mut finished = false;
+	while processed < batch {
+		let Some(kitty_id) = keys.next() else {
+			finished = true;
+			break;
+		};
+		migrate_kitty::<T>(state.from_version, kitty_id);
+		state.cursor = Some(kitty_id);
+		processed += 1;
+	}
+	state.migrated = state.migrated.saturating_add(processed);
+
+	let entries = processed as u64 + 1;
+	(T::DbWeight::get().reads_writes(entries, entries.saturating_sub(1)), finished)
+}
+
+fn migrate_kitty<T: Config>(from_version: u16, kitty_id: KittyId) {
+	let key = v3::Kitties::<T>::hashed_key_for(kitty_id);
+	let Some(raw) = unhashed::get_raw(&key) else { return };
+	// kitties minted while the migration runs are already in the current layout
+	if raw.len() == Kitty::max_encoded_len() {
+		return;
+	}
+
+	match decode_kitty(from_version, &raw) {
+		Some(kitty) => v3::Kitties::<T>::insert(kitty_id, kitty),
+		None => log::warn!("=== lazy migration === kitty {:?} does not decode", kitty_id),
+	}
+}
+
+/// Decodes a kitty stored in either the current layout or the one being migrated from.
+pub fn decode_kitty(from_version: u16, raw: &[u8]) -> Option<Kitty> {
+	if raw.len() == Kitty::max_encoded_len() {
+		return Kitty::decode(&mut &raw[..]).ok();
+	}
+	match from_version {
+		0 => KittyV0::decode(&mut &raw[..]).ok().map(super::v1::migrate_kitty),
+		_ => KittyV1::decode(&mut &raw[..]).ok().map(super::v2::migrate_kitty),
+	}
+}
+
+/// Removes up to `MigrationBatchSize` entries of the indexes keyed by something other than a
+/// kitty. The re-key fills them again one kitty at a time, in the new id format.
+fn clear_indexes<T: Config>(
+	state: &mut MigrationStateOf<T>,
+	started_at: BlockNumberFor<T>,
+) -> (Weight, bool) {
+	let mut budget = T::MigrationBatchSize::get();
+	let mut weight = Weight::zero();
+	let prefixes = [
+		OwnedKitties::<T>::final_prefix(),
+		AuctionsEnding::<T>::final_prefix(),
+		MintsRevealing::<T>::final_prefix(),
+	];
+	for prefix in prefixes {
+		// each map is only cleared once the one before it is empty, so no cursor is kept
+		let result = unhashed::clear_prefix(&prefix, Some(budget), None);
+		weight.saturating_accrue(
+			T::DbWeight::get().reads_writes(result.loops as u64, result.unique as u64),
+		);
+		if result.maybe_cursor.is_some() {
+			return (weight, false);
+		}
+		budget = budget.saturating_sub(result.backend);
+	}
+
+	state.stage = MigrationStage::Rekey { started_at };
+	(weight, false)
+}
+
+/// Moves up to `MigrationBatchSize` kitties into the default collection, in id order and
+/// resuming after the cursor. The upgrade already moved `NextKittyId`, so it bounds the ids.
+fn rekey<T: Config>(
+	state: &mut MigrationStateOf<T>,
+	started_at: BlockNumberFor<T>,
+	now: BlockNumberFor<T>,
+) -> (Weight, bool) {
+	let end = NextKittyId::<T>::get(DEFAULT_COLLECTION_ID);
+	// the default collection keeps the price kitties cost before collections existed
+	let price = Collections::<T>::get(DEFAULT_COLLECTION_ID)
+		.map(|collection| collection.mint_price)
+		.unwrap_or_default();
+
+	let batch = T::MigrationBatchSize::get();
+	let mut next = state.cursor.map_or(0, |last| last.saturating_add(1));
+	let mut processed = 0u32;
+	while processed < batch && next < end {
+		rekey_kitty::<T>(state.from_version, next, price, started_at);
+		state.cursor = Some(next);
+		next += 1;
+		processed += 1;
+	}
+	state.migrated = state.migrated.saturating_add(processed);
+
+	let weight = T::DbWeight::get()
+		.reads(2)
+		.saturating_add(T::DbWeight::get().reads_writes(13, 23).saturating_mul(processed as u64));
+	if next < end {
+		return (weight, false);
+	}
+
+	// this block's queues run as usual once the re-key is done, the earlier ones are behind
+	state.cursor = None;
+	if started_at >= now {
+		return (weight, true);
+	}
+	state.stage = MigrationStage::CatchUp { next: started_at, until: now };
+	(weight, false)
+}
+
+/// Moves one kitty's entries from its plain `KittyId` keys to the default collection, and
+/// adds it back to the owner and queue indexes cleared before.
+fn rekey_kitty<T: Config>(
+	from_version: u16,
+	kitty_id: KittyId,
+	price: BalanceOf<T>,
+	started_at: BlockNumberFor<T>,
+) {
+	let id = (DEFAULT_COLLECTION_ID, kitty_id);
+
+	if let Some(raw) = unhashed::take_raw(&v3::Kitties::<T>::hashed_key_for(kitty_id)) {
+		match decode_kitty(from_version, &raw) {
+			Some(kitty) => Kitties::<T>::insert(id, kitty),
+			None => log::warn!("=== migration v4 === kitty {:?} does not decode", kitty_id),
+		}
+	}
+	if let Some(owner) = take::<T::AccountId>(KittyOwners::<T>::final_prefix(), kitty_id) {
+		if OwnedKitties::<T>::try_mutate(&owner, |owned| owned.try_push(id)).is_err() {
+			log::warn!("=== migration v4 === owner {:?} exceeds MaxKittiesOwned", owner);
+		}
+		KittyOwners::<T>::insert(id, owner);
+	}
+	if let Some((p1, p2)) = take::<(KittyId, KittyId)>(KittyParents::<T>::final_prefix(), kitty_id)
+	{
+		KittyParents::<T>::insert(id, ((DEFAULT_COLLECTION_ID, p1), (DEFAULT_COLLECTION_ID, p2)));
+	}
+	if let Some(generation) = take::<u32>(KittyGenerations::<T>::final_prefix(), kitty_id) {
+		KittyGenerations::<T>::insert(id, generation);
+	}
+	if let Some(at) = take::<BlockNumberFor<T>>(KittyLastBred::<T>::final_prefix(), kitty_id) {
+		KittyLastBred::<T>::insert(id, at);
+	}
+	if let Some(sale_price) = take::<BalanceOf<T>>(KittyOnSale::<T>::final_prefix(), kitty_id) {
+		KittyOnSale::<T>::insert(id, sale_price);
+	}
+	if let Some(paid) = take::<BalanceOf<T>>(KittyMintPrices::<T>::final_prefix(), kitty_id) {
+		KittyMintPrices::<T>::insert(id, paid);
+	}
+	if let Some(deposit) =
+		take::<(T::AccountId, BalanceOf<T>)>(MetadataDeposits::<T>::final_prefix(), kitty_id)
+	{
+		MetadataDeposits::<T>::insert(id, deposit);
+	}
+	if let Some(approved) = take::<T::AccountId>(KittyApprovals::<T>::final_prefix(), kitty_id) {
+		KittyApprovals::<T>::insert(id, approved);
+	}
+
+	if let Some(auction) = take::<AuctionOf<T>>(KittyAuctions::<T>::final_prefix(), kitty_id) {
+		// the block keeps the auctions it held before it was cleared, so there is room
+		if AuctionsEnding::<T>::try_mutate(auction.end, |ending| ending.try_push(id)).is_err() {
+			log::warn!("=== migration v4 === auction of kitty {:?} not queued", kitty_id);
+		}
+		KittyAuctions::<T>::insert(id, auction);
+	}
+	if let Some(mint) = take::<PendingMintV3<T::AccountId, BlockNumberFor<T>>>(
+		PendingMints::<T>::final_prefix(),
+		kitty_id,
+	) {
+		// the reveal block is not stored, and mints due before the upgrade were retried then
+		let reveal_at =
+			mint.committed_at.saturating_add(T::RevealDelay::get().max(One::one())).max(started_at);
+		if MintsRevealing::<T>::try_mutate(reveal_at, |revealing| revealing.try_push(id)).is_err() {
+			return Pallet::<T>::cancel_mint(id, mint.owner, price);
+		}
+		let mint = PendingMint {
+			owner: mint.owner,
+			name: mint.name,
+			price,
+			committed_at: mint.committed_at,
+		};
+		PendingMints::<T>::insert(id, mint);
+	}
+}
+
+/// Takes the value a per-kitty map holds under the plain `KittyId` key it had up to v3.
+fn take<V: Decode>(prefix: [u8; 32], kitty_id: KittyId) -> Option<V> {
+	let key = [&prefix[..], &Blake2_128Concat::hash(&kitty_id.encode())].concat();
+	unhashed::take(&key)
+}
+
+/// Runs the queues of one block skipped while re-keying.
+fn catch_up<T: Config>(
+	state: &mut MigrationStateOf<T>,
+	next: BlockNumberFor<T>,
+	until: BlockNumberFor<T>,
+	now: BlockNumberFor<T>,
+) -> (Weight, bool) {
+	let weight = Pallet::<T>::run_queues(next, now);
+	let next = next.saturating_add(One::one());
+	if next >= until {
+		return (weight, true);
+	}
+	state.stage = MigrationStage::CatchUp { next, until };
+	(weight, false)
+}
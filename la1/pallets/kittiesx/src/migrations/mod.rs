@@ -1,2 +1,11 @@
+pub mod lazy;
 pub mod v1;
 pub mod v2;
+pub mod v3;
+pub mod v4;
+
+/// Every kitties migration in order, for the runtime's `Executive`. Each one checks the on-chain
+/// storage version, so chains that are already upgraded only pay for the version reads.
+/// `DefaultPrice` is the creation price charged before collections, see [`v4::MigrateV3ToV4`].
+pub type Migrations<T, DefaultPrice> =
+	(v1::MigrateV0ToV2<T>, v2::MigrateV1ToV2<T>, v4::MigrateV3ToV4<T, DefaultPrice>);
@@ -1,11 +1,23 @@
 use frame_support::{
-	pallet_prelude::*, storage::StoragePrefixedMap, traits::GetStorageVersion, weights::Weight,
+	pallet_prelude::*,
+	storage::StoragePrefixedMap,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
 };
 
 use frame_support::{migration::storage_key_iter, Blake2_128Concat};
 use frame_system::pallet_prelude::*;
+use sp_std::marker::PhantomData;
 
-use crate::{Kitties, Kitty, KittyId, Pallet, DEFAULT_KITTY_FEATURE};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use super::v3::Kitties;
+use crate::{
+	Kitty, KittyId, MigrationStage, MigrationState, OngoingMigration, Pallet, DEFAULT_KITTY_FEATURE,
+};
 
 #[derive(
 	Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen,
@@ -15,31 +27,63 @@ pub struct KittyV1 {
 	pub name: [u8; 4],
 }
 
-pub fn upgrade<T: crate::Config>() -> Weight {
-	let on_chain_version = Pallet::<T>::on_chain_storage_version();
-	let current_version = Pallet::<T>::current_storage_version();
-
-	if on_chain_version != 1 {
-		return Weight::zero();
+/// The current layout of a version 1 kitty, with the name padded by spaces.
+pub fn migrate_kitty(kitty: KittyV1) -> Kitty {
+	let mut name = [b' '; 8];
+	for (i, &v) in kitty.name.iter().enumerate() {
+		name[i] = v;
 	}
+	Kitty { dna: kitty.dna, name, feature: DEFAULT_KITTY_FEATURE }
+}
 
-	if current_version != 2 {
-		return Weight::zero();
-	}
+/// Schedules the lazy rewrite of the 4-byte named kitties from storage version 1.
+pub struct MigrateV1ToV2<T>(PhantomData<T>);
+
+impl<T: crate::Config> OnRuntimeUpgrade for MigrateV1ToV2<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		let current_version = Pallet::<T>::current_storage_version();
 
-	let module = Kitties::<T>::module_prefix();
-	let item = Kitties::<T>::storage_prefix();
+		if on_chain_version != 1 || current_version < 2 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		// the rewrite itself runs a batch per block from `on_initialize`
+		OngoingMigration::<T>::put(MigrationState {
+			from_version: 1,
+			cursor: None,
+			migrated: 0,
+			stage: MigrationStage::Layout,
+		});
+		StorageVersion::new(2).put::<Pallet<T>>();
+		log::info!("=== migration v1 -> v2 === scheduled");
+
+		T::DbWeight::get().reads_writes(1, 2)
+	}
 
-	for (kitty_id, kitty) in
-		storage_key_iter::<KittyId, KittyV1, Blake2_128Concat>(module, item).drain()
-	{
-		let mut name = [b' '; 8];
-		for (i, &v) in kitty.name.iter().enumerate() {
-			name[i] = v;
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		if Pallet::<T>::on_chain_storage_version() != 1 {
+			return Ok(Vec::new());
 		}
-		let new_kitty = Kitty { dna: kitty.dna, name, feature: DEFAULT_KITTY_FEATURE };
-		Kitties::<T>::insert(kitty_id, new_kitty);
+		let module = Kitties::<T>::module_prefix();
+		let item = Kitties::<T>::storage_prefix();
+		let count = Kitties::<T>::iter_keys().count() as u32;
+		let decoded =
+			storage_key_iter::<KittyId, KittyV1, Blake2_128Concat>(module, item).count() as u32;
+		ensure!(count == decoded, "some kitties do not decode as v1");
+		Ok(count.encode())
 	}
 
-	Weight::zero()
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		if state.is_empty() {
+			return Ok(());
+		}
+		let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre_upgrade state")?;
+		ensure!(Kitties::<T>::iter_keys().count() as u32 == count, "kitty count changed");
+		ensure!(OngoingMigration::<T>::exists(), "lazy migration not scheduled");
+		ensure!(Pallet::<T>::on_chain_storage_version() == 2, "storage version not bumped");
+		Ok(())
+	}
 }
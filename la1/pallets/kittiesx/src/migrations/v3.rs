@@ -0,0 +1,26 @@
+use frame_support::pallet_prelude::*;
+
+use crate::{Kitty, KittyId, Pallet};
+
+// Layout of the per-kitty maps up to v3, keyed by a plain `KittyId` before collections existed.
+// Earlier migrations and the lazy rewrite read and write through these aliases. v3 itself only
+// added the `OwnedKitties` index, which the v4 re-key rebuilds, so v2 chains go straight to v4.
+
+#[frame_support::storage_alias]
+pub type NextKittyId<T: crate::Config> = StorageValue<Pallet<T>, KittyId, ValueQuery>;
+
+#[frame_support::storage_alias]
+pub type Kitties<T: crate::Config> = StorageMap<Pallet<T>, Blake2_128Concat, KittyId, Kitty>;
+
+#[frame_support::storage_alias]
+pub type KittyOwners<T: crate::Config> =
+	StorageMap<Pallet<T>, Blake2_128Concat, KittyId, <T as frame_system::Config>::AccountId>;
+
+#[frame_support::storage_alias]
+pub type OwnedKitties<T: crate::Config> = StorageMap<
+	Pallet<T>,
+	Blake2_128Concat,
+	<T as frame_system::Config>::AccountId,
+	BoundedVec<KittyId, <T as crate::Config>::MaxKittiesOwned>,
+	ValueQuery,
+>;
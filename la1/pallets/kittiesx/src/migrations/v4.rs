@@ -0,0 +1,128 @@
+use frame_support::{
+	pallet_prelude::*,
+	storage::unhashed,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::AccountIdConversion;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use super::v3;
+#[cfg(feature = "try-runtime")]
+use crate::KittyId;
+use crate::{
+	BalanceOf, Collection, Collections, Config, MigrationStage, MigrationState, NextCollectionId,
+	NextKittyId, OngoingMigration, Pallet, DEFAULT_COLLECTION_ID,
+};
+
+/// A pending mint before collections, when every mint reserved the same creation price.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PendingMintV3<AccountId, BlockNumber> {
+	pub owner: AccountId,
+	pub name: [u8; 8],
+	pub committed_at: BlockNumber,
+}
+
+/// Moves every existing kitty into the default collection, re-keying each per-kitty map from
+/// `KittyId` to `(DEFAULT_COLLECTION_ID, KittyId)`. The default collection sells at
+/// `DefaultPrice`, the creation price the pallet charged before collections had their own,
+/// which is also what the pending mints were charged.
+///
+/// Chains still on v2 upgrade straight from there. The `OwnedKitties` index v3 added is cleared
+/// and rebuilt by the re-key either way, so building it first in one unbounded block is skipped.
+///
+/// The upgrade itself only sets up the default collection and schedules the re-key, which
+/// [`lazy::step`](super::lazy::step) then runs in bounded batches from `on_initialize`. A
+/// `Kitties` rewrite that is still in progress is picked up by the re-key, which decodes
+/// either layout. Kitty calls fail with `MigrationOngoing` and the block queues wait until
+/// every kitty has been moved.
+pub struct MigrateV3ToV4<T, DefaultPrice>(PhantomData<(T, DefaultPrice)>);
+
+impl<T: Config, DefaultPrice: Get<BalanceOf<T>>> OnRuntimeUpgrade
+	for MigrateV3ToV4<T, DefaultPrice>
+{
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		let current_version = Pallet::<T>::current_storage_version();
+
+		if !(2..=3).contains(&on_chain_version) || current_version < 4 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		// a rewrite still in progress was stored before `stage` existed, but both layouts start
+		// with `from_version`; without one every kitty is already in the v2 layout
+		let from_version = unhashed::get_raw(&OngoingMigration::<T>::hashed_key())
+			.and_then(|raw| u16::decode(&mut &raw[..]).ok())
+			.unwrap_or(2);
+
+		NextKittyId::<T>::insert(DEFAULT_COLLECTION_ID, v3::NextKittyId::<T>::take());
+		let collection = Collection {
+			owner: T::PalletId::get().into_account_truncating(),
+			metadata: Default::default(),
+			max_supply: None,
+			mint_price: DefaultPrice::get(),
+		};
+		Collections::<T>::insert(DEFAULT_COLLECTION_ID, collection);
+		NextCollectionId::<T>::put(DEFAULT_COLLECTION_ID + 1);
+
+		let started_at = frame_system::Pallet::<T>::block_number();
+		OngoingMigration::<T>::put(MigrationState {
+			from_version,
+			cursor: None,
+			migrated: 0,
+			stage: MigrationStage::ClearIndexes { started_at },
+		});
+
+		StorageVersion::new(4).put::<Pallet<T>>();
+		log::info!(
+			"=== migration v{} -> v4 === re-key scheduled from v{} kitties",
+			on_chain_version,
+			from_version
+		);
+
+		T::DbWeight::get().reads_writes(4, 6)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		if !(2..=3).contains(&Pallet::<T>::on_chain_storage_version()) {
+			return Ok(Vec::new());
+		}
+		let next_kitty_id = v3::NextKittyId::<T>::get();
+		ensure!(
+			v3::Kitties::<T>::iter_keys().all(|kitty_id| kitty_id < next_kitty_id),
+			"kitty ids past NextKittyId would not be re-keyed"
+		);
+		Ok(next_kitty_id.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		if state.is_empty() {
+			return Ok(());
+		}
+		let next_kitty_id =
+			KittyId::decode(&mut &state[..]).map_err(|_| "invalid pre_upgrade state")?;
+		ensure!(
+			NextKittyId::<T>::get(DEFAULT_COLLECTION_ID) == next_kitty_id,
+			"next kitty id not moved"
+		);
+		ensure!(
+			Collections::<T>::contains_key(DEFAULT_COLLECTION_ID),
+			"default collection not created"
+		);
+		ensure!(
+			OngoingMigration::<T>::get()
+				.map_or(false, |state| matches!(state.stage, MigrationStage::ClearIndexes { .. })),
+			"re-key not scheduled"
+		);
+		ensure!(Pallet::<T>::on_chain_storage_version() == 4, "storage version not bumped");
+		Ok(())
+	}
+}
@@ -1,19 +1,25 @@
 use crate as pallet_kittiesx;
+use codec::Encode;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{ConstU16, ConstU64, OnFinalize, OnInitialize, Randomness},
 	PalletId,
 };
 use sp_core::{ConstU128, ConstU32, H256};
+use sp_io::hashing::blake2_256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Perbill,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
 type Balance = u128;
 
 pub const EXISTENTIAL_DEPOSIT: u128 = 1;
+/// Collection seeded by `new_test_ext`, minting at `KittyPledgePrice`.
+pub const COLLECTION_ID: pallet_kittiesx::CollectionId = pallet_kittiesx::DEFAULT_COLLECTION_ID;
+/// Account owning the seeded collection.
+pub const COLLECTION_OWNER: u64 = 0;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -21,7 +27,6 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		KittiesxModule: pallet_kittiesx,
-		Randomness:pallet_insecure_randomness_collective_flip,
 		Balances: pallet_balances,
 	}
 );
@@ -29,6 +34,25 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub KittyPalletId:PalletId =PalletId(*b"kittiesX");
 	pub KittyPledgePrice: Balance = EXISTENTIAL_DEPOSIT *10;
+	pub KittyMarketplaceFee: Perbill = Perbill::from_percent(5);
+	pub KittyMutationRate: Perbill = Perbill::zero();
+	pub KittyMetadataDeposit: Balance = EXISTENTIAL_DEPOSIT * 5;
+	pub KittyBurnRefundRatio: Perbill = Perbill::from_percent(50);
+	pub KittyMaxRoyalty: Perbill = Perbill::from_percent(10);
+	pub static MockEntropy: u64 = 0;
+	pub static MockRandomnessLookback: u64 = 0;
+}
+
+// Randomness that only becomes known at the current block, like a VRF output, unless a
+// lookback is set to mimic sources that expose older material.
+pub struct MockRandomness;
+
+impl Randomness<H256, u64> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		let block_number = System::block_number();
+		let seed = (subject, block_number, MockEntropy::get()).using_encoded(blake2_256);
+		(H256::from(seed), block_number.saturating_sub(MockRandomnessLookback::get()))
+	}
 }
 
 impl frame_system::Config for Test {
@@ -78,18 +102,51 @@ impl pallet_balances::Config for Test {
 impl pallet_kittiesx::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
-	type Randomness = Randomness;
+	type Randomness = MockRandomness;
 	type Currency = Balances;
-	type KittyCreatePrice = KittyPledgePrice;
 	type PalletId = KittyPalletId;
+	type MaxKittiesOwned = ConstU32<8>;
+	type MarketplaceFee = KittyMarketplaceFee;
+	type MaxAuctionsPerBlock = ConstU32<4>;
+	type MutationRate = KittyMutationRate;
+	type MetadataDeposit = KittyMetadataDeposit;
+	type BreedCooldown = ConstU64<5>;
+	type BurnRefundRatio = KittyBurnRefundRatio;
+	type RevealDelay = ConstU64<3>;
+	type MaxMintsPerBlock = ConstU32<4>;
+	type MigrationBatchSize = ConstU32<2>;
+	type MaxMetadataLength = ConstU32<64>;
+	type MaxBatchSize = ConstU32<4>;
+	type MaxRoyalty = KittyMaxRoyalty;
+	type MaxLeasesPerBlock = ConstU32<4>;
 }
 
-impl pallet_insecure_randomness_collective_flip::Config for Test {}
-
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut ext: sp_io::TestExternalities =
 		frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into();
-	ext.execute_with(|| System::set_block_number(1));
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		let collection = pallet_kittiesx::Collection {
+			owner: COLLECTION_OWNER,
+			metadata: Default::default(),
+			max_supply: None,
+			mint_price: KittyPledgePrice::get(),
+		};
+		pallet_kittiesx::Collections::<Test>::insert(COLLECTION_ID, collection);
+		pallet_kittiesx::NextCollectionId::<Test>::put(COLLECTION_ID + 1);
+	});
 	ext
 }
+
+pub fn next_kitty_id() -> pallet_kittiesx::CollectionKittyId {
+	(COLLECTION_ID, KittiesxModule::next_kitty_id(COLLECTION_ID))
+}
+
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		KittiesxModule::on_finalize(System::block_number());
+		System::set_block_number(System::block_number() + 1);
+		KittiesxModule::on_initialize(System::block_number());
+	}
+}
@@ -1,6 +1,35 @@
-use crate::{mock::*, Error, Event, Kitty};
-use frame_support::{assert_noop, assert_ok, traits::fungible::Mutate};
+use crate::{
+	genetics, index,
+	migrations::{
+		v1::{KittyV0, MigrateV0ToV2},
+		v2::{KittyV1, MigrateV1ToV2},
+		v3,
+		v4::MigrateV3ToV4,
+		Migrations,
+	},
+	mock::*,
+	AuctionsEnding, CollectionKittyId, CreatorInfo, Error, Event, Kitty, KittyAuctions,
+	KittyMintPrices, KittyOnSale, KittyParents, MigrationStage,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::{unhashed, StoragePrefixedMap},
+	traits::{
+		fungible::Mutate, GetStorageVersion, Hooks, OnRuntimeUpgrade, ReservableCurrency,
+		StorageVersion,
+	},
+	Blake2_128Concat, StorageHasher,
+};
 use frame_system::Origin;
+use sp_core::{
+	offchain::{testing::TestOffchainExt, OffchainDbExt, OffchainWorkerExt},
+	H256,
+};
+use sp_runtime::{
+	offchain::storage::StorageValueRef, traits::AccountIdConversion, BoundedVec, BuildStorage,
+	Perbill,
+};
 
 const TEST_AMOUNT: u128 = 10000;
 
@@ -22,10 +51,10 @@ fn create_kitty() {
 		let origin = RuntimeOrigin::signed(account_id);
 		Balances::set_balance(&account_id, TEST_AMOUNT);
 
-		let kitty_id = KittiesxModule::next_kitty_id();
-		assert_eq!(KittiesxModule::next_kitty_id(), kitty_id);
-		assert_ok!(KittiesxModule::create(origin));
-		assert_eq!(KittiesxModule::next_kitty_id(), kitty_id + 1);
+		let kitty_id = next_kitty_id();
+		assert_eq!(next_kitty_id(), kitty_id);
+		assert_ok!(KittiesxModule::create(origin, COLLECTION_ID, Perbill::zero()));
+		assert_eq!(next_kitty_id(), (kitty_id.0, kitty_id.1 + 1));
 		assert_eq!(KittiesxModule::kitties(kitty_id).is_none(), false);
 		assert_eq!(KittiesxModule::kitty_owners(kitty_id).is_none(), false);
 		assert_eq!(KittiesxModule::kitty_parents(kitty_id).is_none(), true);
@@ -37,7 +66,10 @@ fn create_kitty_failed_when_not_enough_currency() {
 	new_test_ext().execute_with(|| {
 		let account_id = 1u64;
 		let origin = RuntimeOrigin::signed(account_id);
-		assert_noop!(KittiesxModule::create(origin), Error::<Test>::NotEnoughCurrency);
+		assert_noop!(
+			KittiesxModule::create(origin, COLLECTION_ID, Perbill::zero()),
+			Error::<Test>::NotEnoughCurrency
+		);
 	});
 }
 
@@ -45,11 +77,14 @@ fn create_kitty_failed_when_not_enough_currency() {
 fn create_kitty_failed_when_id_overflow() {
 	new_test_ext().execute_with(|| {
 		let account_id = 1u64;
-		crate::NextKittyId::<Test>::set(crate::KittyId::MAX);
+		crate::NextKittyId::<Test>::insert(COLLECTION_ID, crate::KittyId::MAX);
 		let origin = RuntimeOrigin::signed(account_id);
 		Balances::set_balance(&account_id, TEST_AMOUNT);
 
-		assert_noop!(KittiesxModule::create(origin), Error::<Test>::KittyOverflow);
+		assert_noop!(
+			KittiesxModule::create(origin, COLLECTION_ID, Perbill::zero()),
+			Error::<Test>::KittyOverflow
+		);
 	});
 }
 
@@ -58,17 +93,31 @@ fn breed_kitty() {
 	new_test_ext().execute_with(|| {
 		let account_id = 1u64;
 		Balances::set_balance(&account_id, TEST_AMOUNT);
-		let kitty_id_1 = KittiesxModule::next_kitty_id();
-		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(account_id)));
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
 
 		let account_id = 2u64;
 		Balances::set_balance(&account_id, TEST_AMOUNT);
-		let kitty_id_2 = KittiesxModule::next_kitty_id();
-		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(account_id)));
+		let kitty_id_2 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
 
 		let origin = RuntimeOrigin::signed(account_id);
-		let new_kitty_id = KittiesxModule::next_kitty_id();
-		assert_ok!(KittiesxModule::breed(origin, kitty_id_1, kitty_id_2));
+		let new_kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::breed(
+			origin,
+			COLLECTION_ID,
+			kitty_id_1,
+			kitty_id_2,
+			Perbill::zero()
+		));
 		assert_eq!(KittiesxModule::kitties(new_kitty_id).is_none(), false);
 		assert_eq!(KittiesxModule::kitty_owners(new_kitty_id).is_none(), false);
 		assert_eq!(KittiesxModule::kitty_parents(new_kitty_id).is_none(), false);
@@ -80,12 +129,16 @@ fn breed_kitty_failed_when_kitty_single_parent() {
 	new_test_ext().execute_with(|| {
 		let account_id = 1u64;
 		Balances::set_balance(&account_id, TEST_AMOUNT);
-		let kitty_id_1 = KittiesxModule::next_kitty_id();
-		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(account_id)));
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
 
 		let origin = RuntimeOrigin::signed(account_id);
 		assert_noop!(
-			KittiesxModule::breed(origin, kitty_id_1, kitty_id_1),
+			KittiesxModule::breed(origin, COLLECTION_ID, kitty_id_1, kitty_id_1, Perbill::zero()),
 			Error::<Test>::KittySingleParent
 		);
 	});
@@ -94,11 +147,11 @@ fn breed_kitty_failed_when_kitty_single_parent() {
 #[test]
 fn breed_kitty_failed_when_kitty_invalid_kitty_id() {
 	new_test_ext().execute_with(|| {
-		let kitty_id_1 = 1;
-		let kitty_id_2 = 2;
+		let kitty_id_1 = (COLLECTION_ID, 1);
+		let kitty_id_2 = (COLLECTION_ID, 2);
 		let origin = RuntimeOrigin::signed(1);
 		assert_noop!(
-			KittiesxModule::breed(origin, kitty_id_1, kitty_id_2),
+			KittiesxModule::breed(origin, COLLECTION_ID, kitty_id_1, kitty_id_2, Perbill::zero()),
 			Error::<Test>::InvalidKittyId
 		);
 	});
@@ -109,8 +162,12 @@ fn transfer_kitty() {
 	new_test_ext().execute_with(|| {
 		let account_id = 1u64;
 		Balances::set_balance(&account_id, TEST_AMOUNT);
-		let kitty_id = KittiesxModule::next_kitty_id();
-		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(account_id)));
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
 
 		let recipient_id = 2u64;
 		assert_ok!(KittiesxModule::transfer(
@@ -125,10 +182,14 @@ fn transfer_kitty() {
 #[test]
 fn transfer_kitty_failed_when_errpr_recipient() {
 	new_test_ext().execute_with(|| {
-		let kitty_id = KittiesxModule::next_kitty_id();
+		let kitty_id = next_kitty_id();
 		let account_id = 1u64;
 		Balances::set_balance(&account_id, TEST_AMOUNT);
-		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(account_id)));
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
 		assert_noop!(
 			KittiesxModule::transfer(RuntimeOrigin::signed(account_id), account_id, kitty_id),
 			Error::<Test>::ErrprRecipient
@@ -139,7 +200,7 @@ fn transfer_kitty_failed_when_errpr_recipient() {
 #[test]
 fn transfer_kitty_failed_when_invalid_kitty_id() {
 	new_test_ext().execute_with(|| {
-		let kitty_id = 1;
+		let kitty_id = (COLLECTION_ID, 1);
 		let origin_id = 1u64;
 		let recipient_id = 2u64;
 		assert_noop!(
@@ -152,10 +213,14 @@ fn transfer_kitty_failed_when_invalid_kitty_id() {
 #[test]
 fn transfer_kitty_failed_when_error_kitty_owner() {
 	new_test_ext().execute_with(|| {
-		let kitty_id = KittiesxModule::next_kitty_id();
+		let kitty_id = next_kitty_id();
 		let account_id = 1u64;
 		Balances::set_balance(&account_id, TEST_AMOUNT);
-		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(account_id)));
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
 		let recipient_id = 2u64;
 		assert_noop!(
 			KittiesxModule::transfer(RuntimeOrigin::signed(recipient_id), account_id, kitty_id),
@@ -169,12 +234,26 @@ fn sale_kitty() {
 	new_test_ext().execute_with(|| {
 		let account_id = 1u64;
 		Balances::set_balance(&account_id, TEST_AMOUNT);
-		let kitty_id = KittiesxModule::next_kitty_id();
-		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(account_id)));
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
 		let sale_price = 1234;
 		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(account_id), kitty_id, sale_price));
 		let on_sale_price = KittiesxModule::kitty_on_sale(kitty_id).unwrap();
 		assert_eq!(on_sale_price, sale_price);
+
+		// the kitty is held in escrow while it is listed
+		let escrow = KittiesxModule::escrow_account(kitty_id);
+		assert_eq!(KittiesxModule::kitty_owners(kitty_id), Some(escrow));
+		assert_eq!(KittiesxModule::kitty_sellers(kitty_id), Some(account_id));
+		assert!(KittiesxModule::owned_kitties(account_id).is_empty());
+		assert_noop!(
+			KittiesxModule::transfer(RuntimeOrigin::signed(account_id), 2, kitty_id),
+			Error::<Test>::ErrorKittyOwner
+		);
 	});
 }
 
@@ -183,8 +262,12 @@ fn buy_kitty() {
 	new_test_ext().execute_with(|| {
 		let account_1_id = 1u64;
 		Balances::set_balance(&account_1_id, TEST_AMOUNT);
-		let kitty_id = KittiesxModule::next_kitty_id();
-		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(account_1_id)));
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_1_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
 
 		let sale_price = 1234;
 		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(account_1_id), kitty_id, sale_price));
@@ -192,12 +275,2371 @@ fn buy_kitty() {
 		let account_2_id = 2u64;
 		Balances::set_balance(&account_2_id, TEST_AMOUNT);
 
-		assert_ok!(KittiesxModule::buy(RuntimeOrigin::signed(account_2_id), kitty_id));
+		assert_ok!(KittiesxModule::buy(RuntimeOrigin::signed(account_2_id), kitty_id, sale_price));
 
+		let fee = KittyMarketplaceFee::get().mul_floor(sale_price);
 		let account_1_free_balance = Balances::free_balance(account_1_id);
 		let account_2_free_balance = Balances::free_balance(account_2_id);
-		assert_eq!(account_1_free_balance, TEST_AMOUNT - 10 + sale_price);
+		assert_eq!(account_1_free_balance, TEST_AMOUNT - 10 + sale_price - fee);
 		assert_eq!(account_2_free_balance, TEST_AMOUNT - sale_price);
 		println!("account 1:{:?} account 2:{:?}", account_1_free_balance, account_2_free_balance);
 	});
 }
+
+#[test]
+fn owned_kitties_follow_ownership() {
+	new_test_ext().execute_with(|| {
+		let account_1_id = 1u64;
+		let account_2_id = 2u64;
+		Balances::set_balance(&account_1_id, TEST_AMOUNT);
+		Balances::set_balance(&account_2_id, TEST_AMOUNT);
+
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_1_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_2 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_1_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_3 = next_kitty_id();
+		assert_ok!(KittiesxModule::breed(
+			RuntimeOrigin::signed(account_1_id),
+			COLLECTION_ID,
+			kitty_id_1,
+			kitty_id_2,
+			Perbill::zero()
+		));
+		assert_eq!(
+			KittiesxModule::owned_kitties(account_1_id).into_inner(),
+			vec![kitty_id_1, kitty_id_2, kitty_id_3]
+		);
+
+		assert_ok!(KittiesxModule::transfer(
+			RuntimeOrigin::signed(account_1_id),
+			account_2_id,
+			kitty_id_1
+		));
+		assert_eq!(
+			KittiesxModule::owned_kitties(account_1_id).into_inner(),
+			vec![kitty_id_2, kitty_id_3]
+		);
+		assert_eq!(KittiesxModule::owned_kitties(account_2_id).into_inner(), vec![kitty_id_1]);
+
+		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(account_2_id), kitty_id_1, 100));
+		assert_ok!(KittiesxModule::buy(RuntimeOrigin::signed(account_1_id), kitty_id_1, 100));
+		assert_eq!(
+			KittiesxModule::owned_kitties(account_1_id).into_inner(),
+			vec![kitty_id_2, kitty_id_3, kitty_id_1]
+		);
+		assert!(KittiesxModule::owned_kitties(account_2_id).is_empty());
+	});
+}
+
+#[test]
+fn create_kitty_failed_when_too_many_owned() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		for _ in 0..8 {
+			assert_ok!(KittiesxModule::create(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				Perbill::zero()
+			));
+		}
+		assert_noop!(
+			KittiesxModule::create(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				Perbill::zero()
+			),
+			Error::<Test>::TooManyOwned
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_pays_marketplace_fee() {
+	new_test_ext().execute_with(|| {
+		let account_1_id = 1u64;
+		let account_2_id = 2u64;
+		Balances::set_balance(&account_1_id, TEST_AMOUNT);
+		Balances::set_balance(&account_2_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_1_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(account_1_id), kitty_id, 1000));
+
+		let pallet_account_id: u64 = KittyPalletId::get().into_account_truncating();
+		let pallet_balance = Balances::free_balance(pallet_account_id);
+		assert_ok!(KittiesxModule::buy(RuntimeOrigin::signed(account_2_id), kitty_id, 1000));
+
+		assert_eq!(Balances::free_balance(pallet_account_id), pallet_balance + 50);
+		assert_eq!(Balances::free_balance(account_1_id), TEST_AMOUNT - 10 + 950);
+		assert_eq!(Balances::free_balance(account_2_id), TEST_AMOUNT - 1000);
+		assert_eq!(KittiesxModule::kitty_on_sale(kitty_id), None);
+	});
+}
+
+#[test]
+fn buy_kitty_pays_creator_royalty() {
+	new_test_ext().execute_with(|| {
+		let (creator, seller, buyer) = (1u64, 2u64, 3u64);
+		for account_id in [creator, seller, buyer] {
+			Balances::set_balance(&account_id, TEST_AMOUNT);
+		}
+		let kitty_id = next_kitty_id();
+		let royalty = Perbill::from_percent(10);
+		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(creator), COLLECTION_ID, royalty));
+		assert_eq!(
+			KittiesxModule::kitty_creators(kitty_id),
+			Some(CreatorInfo { creator, royalty })
+		);
+		assert_ok!(KittiesxModule::transfer(RuntimeOrigin::signed(creator), seller, kitty_id));
+		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(seller), kitty_id, 1000));
+
+		let creator_balance = Balances::free_balance(creator);
+		assert_ok!(KittiesxModule::buy(RuntimeOrigin::signed(buyer), kitty_id, 1000));
+		assert_eq!(Balances::free_balance(creator), creator_balance + 100);
+		assert_eq!(Balances::free_balance(seller), TEST_AMOUNT + 850);
+		assert_eq!(Balances::free_balance(buyer), TEST_AMOUNT - 1000);
+		System::assert_last_event(
+			Event::KittyBought {
+				owner: buyer,
+				seller,
+				kitty_id,
+				price: 1000,
+				fee: 50,
+				creator: Some(creator),
+				royalty: 100,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn create_kitty_failed_when_royalty_too_high() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let royalty = Perbill::from_percent(11);
+		assert_noop!(
+			KittiesxModule::create(RuntimeOrigin::signed(account_id), COLLECTION_ID, royalty),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_noop!(
+			KittiesxModule::commit_create(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				royalty
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_failed_when_not_on_sale() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_noop!(
+			KittiesxModule::buy(RuntimeOrigin::signed(2), kitty_id, 1000),
+			Error::<Test>::KittyNotOnSale
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_failed_when_buy_own_kitty() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(account_id), kitty_id, 1000));
+		assert_noop!(
+			KittiesxModule::buy(RuntimeOrigin::signed(account_id), kitty_id, 1000),
+			Error::<Test>::BuyOwnKitty
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_failed_when_price_too_high() {
+	new_test_ext().execute_with(|| {
+		let account_1_id = 1u64;
+		let account_2_id = 2u64;
+		Balances::set_balance(&account_1_id, TEST_AMOUNT);
+		Balances::set_balance(&account_2_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_1_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(account_1_id), kitty_id, 1000));
+		assert_ok!(KittiesxModule::update_price(
+			RuntimeOrigin::signed(account_1_id),
+			kitty_id,
+			2000
+		));
+		assert_noop!(
+			KittiesxModule::buy(RuntimeOrigin::signed(account_2_id), kitty_id, 1000),
+			Error::<Test>::PriceTooHigh
+		);
+	});
+}
+
+#[test]
+fn cancel_sale_kitty() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(account_id), kitty_id, 1000));
+		assert_noop!(
+			KittiesxModule::cancel_sale(RuntimeOrigin::signed(2), kitty_id),
+			Error::<Test>::ErrorKittyOwner
+		);
+		assert_ok!(KittiesxModule::cancel_sale(RuntimeOrigin::signed(account_id), kitty_id));
+		assert_eq!(KittiesxModule::kitty_on_sale(kitty_id), None);
+		assert_eq!(KittiesxModule::kitty_sellers(kitty_id), None);
+		assert_eq!(KittiesxModule::kitty_owners(kitty_id), Some(account_id));
+		assert_eq!(KittiesxModule::owned_kitties(account_id).into_inner(), vec![kitty_id]);
+		assert_noop!(
+			KittiesxModule::cancel_sale(RuntimeOrigin::signed(account_id), kitty_id),
+			Error::<Test>::KittyNotOnSale
+		);
+	});
+}
+
+#[test]
+fn update_price_kitty() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_noop!(
+			KittiesxModule::update_price(RuntimeOrigin::signed(account_id), kitty_id, 2000),
+			Error::<Test>::KittyNotOnSale
+		);
+		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(account_id), kitty_id, 1000));
+		assert_ok!(KittiesxModule::update_price(RuntimeOrigin::signed(account_id), kitty_id, 2000));
+		assert_eq!(KittiesxModule::kitty_on_sale(kitty_id), Some(2000));
+	});
+}
+
+#[test]
+fn listing_from_before_escrow_stays_with_its_owner() {
+	new_test_ext().execute_with(|| {
+		let (seller, buyer) = (1u64, 2u64);
+		Balances::set_balance(&seller, TEST_AMOUNT);
+		Balances::set_balance(&buyer, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(seller),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		// listed by an earlier runtime, which left the kitty with its owner
+		KittyOnSale::<Test>::insert(kitty_id, 1000);
+
+		assert_ok!(KittiesxModule::update_price(RuntimeOrigin::signed(seller), kitty_id, 500));
+		assert_eq!(KittiesxModule::kitty_info(kitty_id).unwrap().owner, seller);
+		assert_ok!(KittiesxModule::buy(RuntimeOrigin::signed(buyer), kitty_id, 500));
+		assert_eq!(KittiesxModule::kitty_owners(kitty_id), Some(buyer));
+		assert_eq!(KittiesxModule::owned_kitties(buyer).into_inner(), vec![kitty_id]);
+		assert!(KittiesxModule::owned_kitties(seller).is_empty());
+		assert_eq!(Balances::free_balance(seller), TEST_AMOUNT - 10 + 475);
+	});
+}
+
+#[test]
+fn auction_kitty() {
+	new_test_ext().execute_with(|| {
+		let account_1_id = 1u64;
+		let account_2_id = 2u64;
+		let account_3_id = 3u64;
+		Balances::set_balance(&account_1_id, TEST_AMOUNT);
+		Balances::set_balance(&account_2_id, TEST_AMOUNT);
+		Balances::set_balance(&account_3_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_1_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::create_auction(
+			RuntimeOrigin::signed(account_1_id),
+			kitty_id,
+			100,
+			5
+		));
+		assert_noop!(
+			KittiesxModule::transfer(RuntimeOrigin::signed(account_1_id), account_2_id, kitty_id),
+			Error::<Test>::KittyInAuction
+		);
+
+		assert_ok!(KittiesxModule::bid(RuntimeOrigin::signed(account_2_id), kitty_id, 100));
+		assert_eq!(Balances::reserved_balance(account_2_id), 100);
+		assert_ok!(KittiesxModule::bid(RuntimeOrigin::signed(account_3_id), kitty_id, 200));
+		assert_eq!(Balances::reserved_balance(account_2_id), 0);
+		assert_eq!(Balances::reserved_balance(account_3_id), 200);
+
+		run_to_block(5);
+		assert_eq!(KittiesxModule::kitty_auctions(kitty_id), None);
+		assert_eq!(KittiesxModule::kitty_owners(kitty_id), Some(account_3_id));
+		assert_eq!(KittiesxModule::owned_kitties(account_3_id).into_inner(), vec![kitty_id]);
+		assert!(KittiesxModule::owned_kitties(account_1_id).is_empty());
+		assert_eq!(Balances::reserved_balance(account_3_id), 0);
+		assert_eq!(Balances::free_balance(account_3_id), TEST_AMOUNT - 200);
+		assert_eq!(Balances::free_balance(account_1_id), TEST_AMOUNT - 10 + 200);
+	});
+}
+
+#[test]
+fn auction_kitty_unsold() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::create_auction(
+			RuntimeOrigin::signed(account_id),
+			kitty_id,
+			100,
+			3
+		));
+
+		run_to_block(3);
+		assert_eq!(KittiesxModule::kitty_auctions(kitty_id), None);
+		assert_eq!(KittiesxModule::kitty_owners(kitty_id), Some(account_id));
+	});
+}
+
+#[test]
+fn create_auction_failed_when_invalid_end() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_noop!(
+			KittiesxModule::create_auction(RuntimeOrigin::signed(account_id), kitty_id, 100, 1),
+			Error::<Test>::InvalidAuctionEnd
+		);
+	});
+}
+
+#[test]
+fn bid_kitty_failed() {
+	new_test_ext().execute_with(|| {
+		let account_1_id = 1u64;
+		let account_2_id = 2u64;
+		Balances::set_balance(&account_1_id, TEST_AMOUNT);
+		Balances::set_balance(&account_2_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_noop!(
+			KittiesxModule::bid(RuntimeOrigin::signed(account_2_id), kitty_id, 100),
+			Error::<Test>::AuctionNotFound
+		);
+
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_1_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::create_auction(
+			RuntimeOrigin::signed(account_1_id),
+			kitty_id,
+			100,
+			3
+		));
+		assert_noop!(
+			KittiesxModule::bid(RuntimeOrigin::signed(account_1_id), kitty_id, 100),
+			Error::<Test>::BidOwnAuction
+		);
+		assert_noop!(
+			KittiesxModule::bid(RuntimeOrigin::signed(account_2_id), kitty_id, 99),
+			Error::<Test>::BidTooLow
+		);
+		assert_noop!(
+			KittiesxModule::bid(RuntimeOrigin::signed(account_2_id), kitty_id, TEST_AMOUNT * 2),
+			Error::<Test>::NotEnoughCurrency
+		);
+		assert_ok!(KittiesxModule::bid(RuntimeOrigin::signed(account_2_id), kitty_id, 100));
+		assert_noop!(
+			KittiesxModule::bid(RuntimeOrigin::signed(account_2_id), kitty_id, 100),
+			Error::<Test>::BidTooLow
+		);
+	});
+}
+
+#[test]
+fn genetics_decode_dominant_allele() {
+	let mut dna = [0u8; 16];
+	// color alleles Cinnamon/White express White, pattern alleles Calico/Calico express Calico
+	dna[0] = 5;
+	dna[1] = 1;
+	dna[2] = 4;
+	dna[3] = 4;
+	let traits = genetics::decode(&dna);
+	assert_eq!(traits.color, genetics::Color::White);
+	assert_eq!(traits.pattern, genetics::Pattern::Calico);
+	assert_eq!(traits.eyes, genetics::Eyes::Brown);
+	assert_eq!(traits.rarity, genetics::Rarity::Uncommon);
+
+	let dna = [5, 5, 4, 4, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0];
+	assert_eq!(genetics::decode(&dna).rarity, genetics::Rarity::Legendary);
+}
+
+#[test]
+fn genetics_inherit_alleles_from_both_parents() {
+	let dna_1 = [10u8; 16];
+	let dna_2 = [20u8; 16];
+	let seed = [0x5au8; 16];
+	let child = genetics::inherit(&dna_1, &dna_2, &seed, Perbill::zero());
+	for gene in 0..genetics::GENE_COUNT {
+		assert_eq!(child[gene * 2], 10);
+		assert_eq!(child[gene * 2 + 1], 20);
+	}
+
+	let mutated = genetics::inherit(&dna_1, &dna_2, &seed, Perbill::one());
+	assert_ne!(mutated[..genetics::GENE_COUNT * 2], child[..genetics::GENE_COUNT * 2]);
+	assert_eq!(mutated[genetics::GENE_COUNT * 2..], child[genetics::GENE_COUNT * 2..]);
+}
+
+#[test]
+fn breed_kitty_inherits_traits() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_2 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::breed(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			kitty_id_1,
+			kitty_id_2,
+			Perbill::zero()
+		));
+
+		let dna_1 = KittiesxModule::kitties(kitty_id_1).unwrap().dna;
+		let dna_2 = KittiesxModule::kitties(kitty_id_2).unwrap().dna;
+		let dna = KittiesxModule::kitties(kitty_id).unwrap().dna;
+		for gene in 0..genetics::GENE_COUNT {
+			assert!(dna_1[gene * 2..gene * 2 + 2].contains(&dna[gene * 2]));
+			assert!(dna_2[gene * 2..gene * 2 + 2].contains(&dna[gene * 2 + 1]));
+		}
+		assert_eq!(KittiesxModule::kitty_traits(kitty_id), Some(genetics::decode(&dna)));
+		assert_eq!(KittiesxModule::kitty_traits((kitty_id.0, kitty_id.1 + 1)), None);
+	});
+}
+
+#[test]
+fn create_kitty_with_name() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		let name = BoundedVec::try_from(b"tom".to_vec()).unwrap();
+		assert_ok!(KittiesxModule::create_with_name(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			name,
+			Perbill::zero()
+		));
+		let kitty = KittiesxModule::kitties(kitty_id).unwrap();
+		assert_eq!(kitty.name, *b"tom     ");
+		assert_eq!(kitty.feature, crate::DEFAULT_KITTY_FEATURE);
+		assert_eq!(KittiesxModule::owned_kitties(account_id).into_inner(), vec![kitty_id]);
+	});
+}
+
+#[test]
+fn create_kitty_with_name_failed_when_invalid_name() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		for name in [b"".to_vec(), vec![0xff, 0xfe], b"to\nm".to_vec()] {
+			assert_noop!(
+				KittiesxModule::create_with_name(
+					RuntimeOrigin::signed(account_id),
+					COLLECTION_ID,
+					BoundedVec::try_from(name).unwrap(),
+					Perbill::zero()
+				),
+				Error::<Test>::InvalidKittyName
+			);
+		}
+	});
+}
+
+#[test]
+fn set_name_and_feature_kitty() {
+	new_test_ext().execute_with(|| {
+		let account_1_id = 1u64;
+		let account_2_id = 2u64;
+		Balances::set_balance(&account_1_id, TEST_AMOUNT);
+		Balances::set_balance(&account_2_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_1_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+
+		let name = BoundedVec::try_from("猫咪".as_bytes().to_vec()).unwrap();
+		assert_ok!(KittiesxModule::set_name(RuntimeOrigin::signed(account_1_id), kitty_id, name));
+		let feature = BoundedVec::try_from(b"lucky".to_vec()).unwrap();
+		assert_ok!(KittiesxModule::set_feature(
+			RuntimeOrigin::signed(account_1_id),
+			kitty_id,
+			feature
+		));
+		let kitty = KittiesxModule::kitties(kitty_id).unwrap();
+		assert_eq!(&kitty.name[..6], "猫咪".as_bytes());
+		assert_eq!(kitty.feature, *b"lucky");
+
+		// the deposit is held once and goes back to the depositor when the kitty changes hands
+		let deposit = KittyMetadataDeposit::get();
+		assert_eq!(Balances::reserved_balance(account_1_id), deposit);
+		assert_ok!(KittiesxModule::transfer(
+			RuntimeOrigin::signed(account_1_id),
+			account_2_id,
+			kitty_id
+		));
+		assert_eq!(Balances::reserved_balance(account_1_id), 0);
+		assert_eq!(KittiesxModule::metadata_deposits(kitty_id), None);
+
+		let name = BoundedVec::try_from(b"jerry".to_vec()).unwrap();
+		assert_ok!(KittiesxModule::set_name(RuntimeOrigin::signed(account_2_id), kitty_id, name));
+		assert_eq!(Balances::reserved_balance(account_2_id), deposit);
+		assert_eq!(KittiesxModule::metadata_deposits(kitty_id), Some((account_2_id, deposit)));
+	});
+}
+
+#[test]
+fn metadata_deposit_released_when_kitty_is_bought() {
+	new_test_ext().execute_with(|| {
+		let account_1_id = 1u64;
+		let account_2_id = 2u64;
+		Balances::set_balance(&account_1_id, TEST_AMOUNT);
+		Balances::set_balance(&account_2_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_1_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let name = BoundedVec::try_from(b"tom".to_vec()).unwrap();
+		assert_ok!(KittiesxModule::set_name(RuntimeOrigin::signed(account_1_id), kitty_id, name));
+		assert_eq!(Balances::reserved_balance(account_1_id), KittyMetadataDeposit::get());
+
+		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(account_1_id), kitty_id, 100));
+		assert_ok!(KittiesxModule::buy(RuntimeOrigin::signed(account_2_id), kitty_id, 100));
+
+		assert_eq!(Balances::reserved_balance(account_1_id), 0);
+		assert_eq!(Balances::reserved_balance(account_2_id), 0);
+		assert_eq!(KittiesxModule::metadata_deposits(kitty_id), None);
+	});
+}
+
+#[test]
+fn set_name_kitty_failed() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		let name: crate::KittyName = BoundedVec::try_from(b"tom".to_vec()).unwrap();
+		assert_noop!(
+			KittiesxModule::set_name(RuntimeOrigin::signed(account_id), kitty_id, name.clone()),
+			Error::<Test>::InvalidKittyId
+		);
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_noop!(
+			KittiesxModule::set_name(RuntimeOrigin::signed(2), kitty_id, name),
+			Error::<Test>::ErrorKittyOwner
+		);
+		assert_noop!(
+			KittiesxModule::set_feature(
+				RuntimeOrigin::signed(account_id),
+				kitty_id,
+				BoundedVec::try_from(b"\t".to_vec()).unwrap()
+			),
+			Error::<Test>::InvalidKittyFeature
+		);
+	});
+}
+
+#[test]
+fn breed_kitty_tracks_generation_and_cooldown() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_2 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_3 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+
+		let kitty_id_4 = next_kitty_id();
+		assert_ok!(KittiesxModule::breed(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			kitty_id_1,
+			kitty_id_2,
+			Perbill::zero()
+		));
+		assert_eq!(KittiesxModule::kitty_generations(kitty_id_4), 1);
+		assert_eq!(KittiesxModule::kitty_last_bred(kitty_id_1), Some(1));
+		assert_noop!(
+			KittiesxModule::breed(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				kitty_id_1,
+				kitty_id_3,
+				Perbill::zero()
+			),
+			Error::<Test>::KittyInCooldown
+		);
+
+		System::set_block_number(6);
+		let kitty_id_5 = next_kitty_id();
+		assert_ok!(KittiesxModule::breed(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			kitty_id_4,
+			kitty_id_3,
+			Perbill::zero()
+		));
+		assert_eq!(KittiesxModule::kitty_generations(kitty_id_5), 2);
+	});
+}
+
+#[test]
+fn breed_kitty_failed_when_kitty_related() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_2 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_3 = next_kitty_id();
+		assert_ok!(KittiesxModule::breed(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			kitty_id_1,
+			kitty_id_2,
+			Perbill::zero()
+		));
+
+		System::set_block_number(6);
+		let kitty_id_4 = next_kitty_id();
+		assert_ok!(KittiesxModule::breed(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			kitty_id_1,
+			kitty_id_2,
+			Perbill::zero()
+		));
+
+		System::set_block_number(11);
+		// parent and child
+		assert_noop!(
+			KittiesxModule::breed(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				kitty_id_3,
+				kitty_id_1,
+				Perbill::zero()
+			),
+			Error::<Test>::KittyRelated
+		);
+		// siblings
+		assert_noop!(
+			KittiesxModule::breed(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				kitty_id_3,
+				kitty_id_4,
+				Perbill::zero()
+			),
+			Error::<Test>::KittyRelated
+		);
+	});
+}
+
+#[test]
+fn kitty_ancestry() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_2 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_3 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_4 = next_kitty_id();
+		assert_ok!(KittiesxModule::breed(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			kitty_id_1,
+			kitty_id_2,
+			Perbill::zero()
+		));
+		System::set_block_number(6);
+		let kitty_id_5 = next_kitty_id();
+		assert_ok!(KittiesxModule::breed(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			kitty_id_4,
+			kitty_id_3,
+			Perbill::zero()
+		));
+
+		let ancestry = KittiesxModule::kitty_ancestry(kitty_id_5, 1);
+		let ids: Vec<_> = ancestry.iter().map(|node| (node.kitty_id, node.depth)).collect();
+		assert_eq!(ids, vec![(kitty_id_5, 0), (kitty_id_4, 1), (kitty_id_3, 1)]);
+		assert_eq!(ancestry[0].generation, 2);
+		assert_eq!(ancestry[0].parents, Some((kitty_id_4, kitty_id_3)));
+
+		let ancestry = KittiesxModule::kitty_ancestry(kitty_id_5, 10);
+		assert_eq!(ancestry.len(), 5);
+		assert!(KittiesxModule::kitty_ancestry((kitty_id_5.0, kitty_id_5.1 + 1), 10).is_empty());
+	});
+}
+
+#[test]
+fn burn_kitty() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_2 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_3 = next_kitty_id();
+		assert_ok!(KittiesxModule::breed(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			kitty_id_1,
+			kitty_id_2,
+			Perbill::zero()
+		));
+
+		// a listed kitty sits in escrow, so the sale has to be cancelled first
+		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(account_id), kitty_id_1, 100));
+		assert_noop!(
+			KittiesxModule::burn(RuntimeOrigin::signed(account_id), kitty_id_1),
+			Error::<Test>::ErrorKittyOwner
+		);
+		assert_ok!(KittiesxModule::cancel_sale(RuntimeOrigin::signed(account_id), kitty_id_1));
+		let name = BoundedVec::try_from(b"tom".to_vec()).unwrap();
+		assert_ok!(KittiesxModule::set_name(RuntimeOrigin::signed(account_id), kitty_id_1, name));
+		let balance = Balances::free_balance(account_id);
+
+		assert_ok!(KittiesxModule::burn(RuntimeOrigin::signed(account_id), kitty_id_1));
+		assert_eq!(KittiesxModule::kitties(kitty_id_1), None);
+		assert_eq!(KittiesxModule::kitty_owners(kitty_id_1), None);
+		assert_eq!(KittiesxModule::kitty_on_sale(kitty_id_1), None);
+		assert_eq!(KittiesxModule::metadata_deposits(kitty_id_1), None);
+		assert_eq!(
+			KittiesxModule::owned_kitties(account_id).into_inner(),
+			vec![kitty_id_2, kitty_id_3]
+		);
+		let deposit = KittyMetadataDeposit::get();
+		let refund = KittyBurnRefundRatio::get().mul_floor(KittyPledgePrice::get());
+		assert_eq!(Balances::reserved_balance(account_id), 0);
+		assert_eq!(Balances::free_balance(account_id), balance + deposit + refund);
+
+		// the child still resolves its burned parent
+		assert_eq!(KittiesxModule::kitty_parents(kitty_id_3), Some((kitty_id_1, kitty_id_2)));
+		let ancestry = KittiesxModule::kitty_ancestry(kitty_id_3, 1);
+		assert_eq!(ancestry.len(), 3);
+		assert!(ancestry[1].burned);
+		assert!(!ancestry[2].burned);
+	});
+}
+
+#[test]
+fn burn_bred_kitty_refunds_only_what_was_paid() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_2 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_eq!(KittiesxModule::kitty_mint_prices(kitty_id_1), Some(KittyPledgePrice::get()));
+
+		// breeding for free must not turn into a refund from the pallet account
+		assert_ok!(KittiesxModule::set_mint_price(
+			RuntimeOrigin::signed(COLLECTION_OWNER),
+			COLLECTION_ID,
+			0
+		));
+		let kitty_id_3 = next_kitty_id();
+		assert_ok!(KittiesxModule::breed(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			kitty_id_1,
+			kitty_id_2,
+			Perbill::zero()
+		));
+		assert_eq!(KittiesxModule::kitty_mint_prices(kitty_id_3), None);
+
+		let pallet_account_id: u64 = KittyPalletId::get().into_account_truncating();
+		let pallet_balance = Balances::free_balance(pallet_account_id);
+		let balance = Balances::free_balance(account_id);
+		assert_ok!(KittiesxModule::burn(RuntimeOrigin::signed(account_id), kitty_id_3));
+		System::assert_last_event(
+			Event::KittyBurned { owner: account_id, kitty_id: kitty_id_3, refund: 0 }.into(),
+		);
+		assert_eq!(Balances::free_balance(account_id), balance);
+		assert_eq!(Balances::free_balance(pallet_account_id), pallet_balance);
+	});
+}
+
+#[test]
+fn burn_kitty_failed() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_noop!(
+			KittiesxModule::burn(RuntimeOrigin::signed(account_id), kitty_id),
+			Error::<Test>::InvalidKittyId
+		);
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_noop!(
+			KittiesxModule::burn(RuntimeOrigin::signed(2), kitty_id),
+			Error::<Test>::ErrorKittyOwner
+		);
+		assert_ok!(KittiesxModule::create_auction(
+			RuntimeOrigin::signed(account_id),
+			kitty_id,
+			100,
+			3
+		));
+		assert_noop!(
+			KittiesxModule::burn(RuntimeOrigin::signed(account_id), kitty_id),
+			Error::<Test>::KittyInAuction
+		);
+	});
+}
+
+#[test]
+fn transfer_from_kitty_with_approval() {
+	new_test_ext().execute_with(|| {
+		let owner_id = 1u64;
+		let spender_id = 2u64;
+		let recipient_id = 3u64;
+		Balances::set_balance(&owner_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(owner_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+
+		assert_noop!(
+			KittiesxModule::transfer_from(
+				RuntimeOrigin::signed(spender_id),
+				owner_id,
+				recipient_id,
+				kitty_id
+			),
+			Error::<Test>::NotApproved
+		);
+		assert_ok!(KittiesxModule::approve(RuntimeOrigin::signed(owner_id), kitty_id, spender_id));
+		assert_eq!(KittiesxModule::kitty_approvals(kitty_id), Some(spender_id));
+		assert_ok!(KittiesxModule::transfer_from(
+			RuntimeOrigin::signed(spender_id),
+			owner_id,
+			recipient_id,
+			kitty_id
+		));
+		assert_eq!(KittiesxModule::kitty_owners(kitty_id), Some(recipient_id));
+		assert_eq!(KittiesxModule::owned_kitties(recipient_id).into_inner(), vec![kitty_id]);
+		assert_eq!(KittiesxModule::kitty_approvals(kitty_id), None);
+	});
+}
+
+#[test]
+fn transfer_from_kitty_after_approval_cancelled() {
+	new_test_ext().execute_with(|| {
+		let owner_id = 1u64;
+		let spender_id = 2u64;
+		let recipient_id = 3u64;
+		Balances::set_balance(&owner_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(owner_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+
+		assert_noop!(
+			KittiesxModule::cancel_approval(RuntimeOrigin::signed(owner_id), kitty_id),
+			Error::<Test>::NotApproved
+		);
+		assert_ok!(KittiesxModule::approve(RuntimeOrigin::signed(owner_id), kitty_id, spender_id));
+		assert_noop!(
+			KittiesxModule::cancel_approval(RuntimeOrigin::signed(spender_id), kitty_id),
+			Error::<Test>::ErrorKittyOwner
+		);
+		assert_ok!(KittiesxModule::cancel_approval(RuntimeOrigin::signed(owner_id), kitty_id));
+		assert_eq!(KittiesxModule::kitty_approvals(kitty_id), None);
+		System::assert_last_event(
+			Event::KittyApprovalCancelled { owner: owner_id, spender: spender_id, kitty_id }.into(),
+		);
+
+		assert_noop!(
+			KittiesxModule::transfer_from(
+				RuntimeOrigin::signed(spender_id),
+				owner_id,
+				recipient_id,
+				kitty_id
+			),
+			Error::<Test>::NotApproved
+		);
+		assert_eq!(KittiesxModule::kitty_owners(kitty_id), Some(owner_id));
+	});
+}
+
+#[test]
+fn transfer_from_kitty_with_operator() {
+	new_test_ext().execute_with(|| {
+		let owner_id = 1u64;
+		let operator_id = 2u64;
+		let recipient_id = 3u64;
+		Balances::set_balance(&owner_id, TEST_AMOUNT);
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(owner_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_2 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(owner_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+
+		assert_ok!(KittiesxModule::set_approval_for_all(
+			RuntimeOrigin::signed(owner_id),
+			operator_id,
+			true
+		));
+		// operators may also approve single kitties on behalf of the owner
+		assert_ok!(KittiesxModule::approve(
+			RuntimeOrigin::signed(operator_id),
+			kitty_id_2,
+			recipient_id
+		));
+		assert_ok!(KittiesxModule::transfer_from(
+			RuntimeOrigin::signed(operator_id),
+			owner_id,
+			recipient_id,
+			kitty_id_1
+		));
+		assert_eq!(KittiesxModule::kitty_owners(kitty_id_1), Some(recipient_id));
+
+		assert_ok!(KittiesxModule::set_approval_for_all(
+			RuntimeOrigin::signed(owner_id),
+			operator_id,
+			false
+		));
+		assert_noop!(
+			KittiesxModule::transfer_from(
+				RuntimeOrigin::signed(operator_id),
+				owner_id,
+				recipient_id,
+				kitty_id_2
+			),
+			Error::<Test>::NotApproved
+		);
+	});
+}
+
+#[test]
+fn approval_cleared_on_ownership_change() {
+	new_test_ext().execute_with(|| {
+		let owner_id = 1u64;
+		let spender_id = 2u64;
+		let buyer_id = 3u64;
+		Balances::set_balance(&owner_id, TEST_AMOUNT);
+		Balances::set_balance(&buyer_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(owner_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+
+		assert_ok!(KittiesxModule::approve(RuntimeOrigin::signed(owner_id), kitty_id, spender_id));
+		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(owner_id), kitty_id, 100));
+		assert_ok!(KittiesxModule::buy(RuntimeOrigin::signed(buyer_id), kitty_id, 100));
+		assert_eq!(KittiesxModule::kitty_approvals(kitty_id), None);
+
+		assert_ok!(KittiesxModule::approve(RuntimeOrigin::signed(buyer_id), kitty_id, spender_id));
+		assert_ok!(KittiesxModule::transfer(RuntimeOrigin::signed(buyer_id), owner_id, kitty_id));
+		assert_eq!(KittiesxModule::kitty_approvals(kitty_id), None);
+	});
+}
+
+#[test]
+fn approve_kitty_failed() {
+	new_test_ext().execute_with(|| {
+		let owner_id = 1u64;
+		Balances::set_balance(&owner_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_noop!(
+			KittiesxModule::approve(RuntimeOrigin::signed(owner_id), kitty_id, 2),
+			Error::<Test>::InvalidKittyId
+		);
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(owner_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_noop!(
+			KittiesxModule::approve(RuntimeOrigin::signed(2), kitty_id, 3),
+			Error::<Test>::ErrorKittyOwner
+		);
+		assert_noop!(
+			KittiesxModule::approve(RuntimeOrigin::signed(owner_id), kitty_id, owner_id),
+			Error::<Test>::ApproveSelf
+		);
+		assert_noop!(
+			KittiesxModule::set_approval_for_all(RuntimeOrigin::signed(owner_id), owner_id, true),
+			Error::<Test>::ApproveSelf
+		);
+	});
+}
+
+fn commit_and_reveal(entropy: u64) -> [u8; 16] {
+	let mut dna = [0u8; 16];
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::commit_create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+
+		// the block author only learns the entropy after the commitment is on chain
+		MockEntropy::set(entropy);
+		run_to_block(4);
+		dna = KittiesxModule::kitties(kitty_id).unwrap().dna;
+	});
+	dna
+}
+
+#[test]
+fn commit_create_kitty() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		let pallet_account_id: u64 = KittyPalletId::get().into_account_truncating();
+
+		assert_ok!(KittiesxModule::commit_create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_eq!(next_kitty_id(), (kitty_id.0, kitty_id.1 + 1));
+		assert_eq!(KittiesxModule::kitties(kitty_id), None);
+		assert_eq!(KittiesxModule::pending_mints(kitty_id).unwrap().owner, account_id);
+		assert_eq!(KittiesxModule::mints_revealing(4).into_inner(), vec![kitty_id]);
+		assert_eq!(Balances::free_balance(pallet_account_id), KittyPledgePrice::get());
+
+		run_to_block(3);
+		assert_eq!(KittiesxModule::kitties(kitty_id), None);
+		run_to_block(4);
+		assert!(KittiesxModule::kitties(kitty_id).is_some());
+		assert_eq!(KittiesxModule::kitty_owners(kitty_id), Some(account_id));
+		assert_eq!(KittiesxModule::owned_kitties(account_id).into_inner(), vec![kitty_id]);
+		assert_eq!(KittiesxModule::pending_mints(kitty_id), None);
+	});
+}
+
+#[test]
+fn commit_create_dna_unknown_at_submission() {
+	// identical state at submission, only the randomness of the reveal block differs
+	assert_ne!(commit_and_reveal(1), commit_and_reveal(2));
+	assert_eq!(commit_and_reveal(1), commit_and_reveal(1));
+}
+
+#[test]
+fn commit_create_waits_for_fresh_randomness() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::commit_create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+
+		// at block 4 the source would still serve randomness known since block 1
+		MockRandomnessLookback::set(3);
+		run_to_block(4);
+		assert_eq!(KittiesxModule::kitties(kitty_id), None);
+		assert_eq!(KittiesxModule::mints_revealing(5).into_inner(), vec![kitty_id]);
+		run_to_block(5);
+		assert!(KittiesxModule::kitties(kitty_id).is_some());
+		MockRandomnessLookback::set(0);
+	});
+}
+
+#[test]
+fn commit_create_cancelled_when_too_many_owned() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::commit_create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		for _ in 0..8 {
+			assert_ok!(KittiesxModule::create(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				Perbill::zero()
+			));
+		}
+		let balance = Balances::free_balance(&account_id);
+
+		run_to_block(4);
+		assert_eq!(KittiesxModule::kitties(kitty_id), None);
+		assert_eq!(KittiesxModule::pending_mints(kitty_id), None);
+		assert_eq!(Balances::free_balance(&account_id), balance + KittyPledgePrice::get());
+	});
+}
+
+#[test]
+fn commit_create_kitty_failed() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		assert_noop!(
+			KittiesxModule::commit_create(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				Perbill::zero()
+			),
+			Error::<Test>::NotEnoughCurrency
+		);
+
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		for _ in 0..4 {
+			assert_ok!(KittiesxModule::commit_create(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				Perbill::zero()
+			));
+		}
+		assert_noop!(
+			KittiesxModule::commit_create(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				Perbill::zero()
+			),
+			Error::<Test>::TooManyMints
+		);
+	});
+}
+
+fn create_test_collection(owner: u64, max_supply: Option<u32>, mint_price: u128) -> u32 {
+	let collection_id = KittiesxModule::next_collection_id();
+	let metadata = BoundedVec::try_from(b"ipfs://kitties".to_vec()).unwrap();
+	assert_ok!(KittiesxModule::create_collection(
+		RuntimeOrigin::signed(owner),
+		metadata,
+		max_supply,
+		mint_price
+	));
+	collection_id
+}
+
+#[test]
+fn create_collection_and_mint() {
+	new_test_ext().execute_with(|| {
+		let (creator, account_id) = (1u64, 2u64);
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let collection_id = create_test_collection(creator, Some(2), 20);
+		assert_eq!(collection_id, COLLECTION_ID + 1);
+		System::assert_last_event(
+			Event::CollectionCreated {
+				owner: creator,
+				collection_id,
+				max_supply: Some(2),
+				mint_price: 20,
+			}
+			.into(),
+		);
+
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			collection_id,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			collection_id,
+			Perbill::zero()
+		));
+		assert_eq!(Balances::free_balance(account_id), TEST_AMOUNT - 40);
+		assert_eq!(
+			KittiesxModule::owned_kitties(account_id).into_inner(),
+			vec![(collection_id, 0), (collection_id, 1)]
+		);
+		assert_noop!(
+			KittiesxModule::create(
+				RuntimeOrigin::signed(account_id),
+				collection_id,
+				Perbill::zero()
+			),
+			Error::<Test>::MaxSupplyReached
+		);
+
+		// ids are numbered per collection
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert!(KittiesxModule::kitties((COLLECTION_ID, 0)).is_some());
+
+		// the refund follows what was paid for the kitty
+		let balance = Balances::free_balance(account_id);
+		assert_ok!(KittiesxModule::burn(RuntimeOrigin::signed(account_id), (collection_id, 0)));
+		assert_eq!(KittiesxModule::kitty_mint_prices((collection_id, 0)), None);
+		let refund = KittyBurnRefundRatio::get().mul_floor(20u128);
+		assert_eq!(Balances::free_balance(account_id), balance + refund);
+	});
+}
+
+#[test]
+fn create_kitty_failed_when_invalid_collection() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		assert_noop!(
+			KittiesxModule::create(RuntimeOrigin::signed(account_id), 7, Perbill::zero()),
+			Error::<Test>::InvalidCollectionId
+		);
+		assert_noop!(
+			KittiesxModule::commit_create(RuntimeOrigin::signed(account_id), 7, Perbill::zero()),
+			Error::<Test>::InvalidCollectionId
+		);
+	});
+}
+
+#[test]
+fn breed_kitty_into_collection() {
+	new_test_ext().execute_with(|| {
+		let (creator, account_id) = (1u64, 2u64);
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let collection_id = create_test_collection(creator, None, 30);
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_2 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let balance = Balances::free_balance(account_id);
+
+		assert_ok!(KittiesxModule::breed(
+			RuntimeOrigin::signed(account_id),
+			collection_id,
+			kitty_id_1,
+			kitty_id_2,
+			Perbill::zero()
+		));
+		let child_id = (collection_id, 0);
+		assert_eq!(KittiesxModule::kitty_owners(child_id), Some(account_id));
+		assert_eq!(KittiesxModule::kitty_parents(child_id), Some((kitty_id_1, kitty_id_2)));
+		assert_eq!(KittiesxModule::kitty_mint_prices(child_id), Some(30));
+		assert_eq!(Balances::free_balance(account_id), balance - 30);
+	});
+}
+
+#[test]
+fn set_collection_metadata_and_mint_price() {
+	new_test_ext().execute_with(|| {
+		let (creator, account_id) = (1u64, 2u64);
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let collection_id = create_test_collection(creator, None, 20);
+
+		let metadata: BoundedVec<u8, _> = BoundedVec::try_from(b"ipfs://cats".to_vec()).unwrap();
+		assert_ok!(KittiesxModule::set_collection_metadata(
+			RuntimeOrigin::signed(creator),
+			collection_id,
+			metadata.clone()
+		));
+		assert_eq!(KittiesxModule::collections(collection_id).unwrap().metadata, metadata);
+		System::assert_last_event(
+			Event::CollectionMetadataSet { owner: creator, collection_id, metadata }.into(),
+		);
+
+		assert_ok!(KittiesxModule::set_mint_price(
+			RuntimeOrigin::signed(creator),
+			collection_id,
+			50
+		));
+		System::assert_last_event(
+			Event::CollectionMintPriceSet { owner: creator, collection_id, mint_price: 50 }.into(),
+		);
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			collection_id,
+			Perbill::zero()
+		));
+		assert_eq!(Balances::free_balance(account_id), TEST_AMOUNT - 50);
+	});
+}
+
+#[test]
+fn set_collection_failed() {
+	new_test_ext().execute_with(|| {
+		let (creator, account_id) = (1u64, 2u64);
+		let collection_id = create_test_collection(creator, None, 20);
+		let metadata = BoundedVec::try_from(b"ipfs://cats".to_vec()).unwrap();
+
+		assert_noop!(
+			KittiesxModule::set_collection_metadata(
+				RuntimeOrigin::signed(account_id),
+				collection_id,
+				metadata.clone()
+			),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_noop!(
+			KittiesxModule::set_collection_metadata(RuntimeOrigin::signed(creator), 7, metadata),
+			Error::<Test>::InvalidCollectionId
+		);
+		assert_noop!(
+			KittiesxModule::set_mint_price(RuntimeOrigin::signed(account_id), collection_id, 1),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_noop!(
+			KittiesxModule::set_mint_price(RuntimeOrigin::signed(creator), 7, 1),
+			Error::<Test>::InvalidCollectionId
+		);
+	});
+}
+
+#[test]
+fn create_batch_kitties() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		assert_ok!(KittiesxModule::create_batch(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			3,
+			Perbill::zero()
+		));
+
+		let owned = KittiesxModule::owned_kitties(account_id).into_inner();
+		assert_eq!(owned, vec![(COLLECTION_ID, 0), (COLLECTION_ID, 1), (COLLECTION_ID, 2)]);
+		assert_eq!(Balances::free_balance(account_id), TEST_AMOUNT - 3 * KittyPledgePrice::get());
+		// the kitties share a block and an extrinsic, yet each gets its own DNA
+		let dna: Vec<[u8; 16]> =
+			owned.iter().map(|kitty_id| KittiesxModule::kitties(kitty_id).unwrap().dna).collect();
+		assert_ne!(dna[0], dna[1]);
+		assert_ne!(dna[0], dna[2]);
+		assert_ne!(dna[1], dna[2]);
+		let created = System::events()
+			.into_iter()
+			.filter(|record| {
+				matches!(record.event, RuntimeEvent::KittiesxModule(Event::KittyCreated { .. }))
+			})
+			.count();
+		assert_eq!(created, 3);
+	});
+}
+
+#[test]
+fn create_batch_kitties_failed() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		assert_noop!(
+			KittiesxModule::create_batch(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				0,
+				Perbill::zero()
+			),
+			Error::<Test>::EmptyBatch
+		);
+		assert_noop!(
+			KittiesxModule::create_batch(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				5,
+				Perbill::zero()
+			),
+			Error::<Test>::BatchTooLarge
+		);
+
+		// the whole batch is reverted when the balance runs out part way
+		let collection_id = create_test_collection(2, None, 4000);
+		assert_noop!(
+			KittiesxModule::create_batch(
+				RuntimeOrigin::signed(account_id),
+				collection_id,
+				3,
+				Perbill::zero()
+			),
+			Error::<Test>::NotEnoughCurrency
+		);
+	});
+}
+
+#[test]
+fn transfer_batch_kitties() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		assert_ok!(KittiesxModule::create_batch(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			3,
+			Perbill::zero()
+		));
+
+		let transfers =
+			vec![(2u64, (COLLECTION_ID, 0)), (3, (COLLECTION_ID, 1)), (2, (COLLECTION_ID, 2))];
+		assert_ok!(KittiesxModule::transfer_batch(
+			RuntimeOrigin::signed(account_id),
+			BoundedVec::try_from(transfers).unwrap()
+		));
+		assert!(KittiesxModule::owned_kitties(account_id).is_empty());
+		assert_eq!(
+			KittiesxModule::owned_kitties(2).into_inner(),
+			vec![(COLLECTION_ID, 0), (COLLECTION_ID, 2)]
+		);
+		assert_eq!(KittiesxModule::kitty_owners((COLLECTION_ID, 1)), Some(3));
+		System::assert_last_event(
+			Event::KittyTransfer { owner: account_id, recipient: 2, kitty_id: (COLLECTION_ID, 2) }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn transfer_batch_kitties_failed() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		Balances::set_balance(&2, TEST_AMOUNT);
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(2),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+
+		assert_noop!(
+			KittiesxModule::transfer_batch(
+				RuntimeOrigin::signed(account_id),
+				BoundedVec::default()
+			),
+			Error::<Test>::EmptyBatch
+		);
+		// the first transfer is reverted along with the one that fails
+		let transfers = vec![(3u64, (COLLECTION_ID, 0)), (3, (COLLECTION_ID, 1))];
+		assert_noop!(
+			KittiesxModule::transfer_batch(
+				RuntimeOrigin::signed(account_id),
+				BoundedVec::try_from(transfers).unwrap()
+			),
+			Error::<Test>::ErrorKittyOwner
+		);
+	});
+}
+
+// key of a per-kitty map entry from before kitties were keyed by collection
+fn old_kitty_key(prefix: [u8; 32], kitty_id: u32) -> Vec<u8> {
+	[&prefix[..], &Blake2_128Concat::hash(&kitty_id.encode())].concat()
+}
+
+// runs blocks until the lazy migration is done
+fn run_migration() {
+	for _ in 0..100 {
+		if KittiesxModule::ongoing_migration().is_none() {
+			return;
+		}
+		run_to_block(System::block_number() + 1);
+	}
+	panic!("lazy migration did not finish");
+}
+
+#[test]
+fn migrate_v0_to_v4() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<KittiesxModule>();
+		for (kitty_id, dna) in [(0u32, [1u8; 16]), (1, [2u8; 16])] {
+			unhashed::put(&v3::Kitties::<Test>::hashed_key_for(kitty_id), &KittyV0(dna));
+			v3::KittyOwners::<Test>::insert(kitty_id, 1u64);
+		}
+		v3::NextKittyId::<Test>::put(2);
+		crate::Collections::<Test>::remove(COLLECTION_ID);
+
+		Migrations::<Test, KittyPledgePrice>::on_runtime_upgrade();
+		assert_eq!(KittiesxModule::on_chain_storage_version(), 4);
+		// the pending layout rewrite is left to the re-key, which decodes v0 kitties itself
+		let state = KittiesxModule::ongoing_migration().unwrap();
+		assert_eq!(state.from_version, 0);
+		assert_eq!(state.stage, MigrationStage::ClearIndexes { started_at: 1 });
+		assert_eq!(KittiesxModule::kitties((COLLECTION_ID, 1)), None);
+
+		run_migration();
+		assert_eq!(
+			KittiesxModule::kitties((COLLECTION_ID, 1)),
+			Some(Kitty { dna: [2u8; 16], name: *b"mimimimi", feature: *b"happy" })
+		);
+		assert_eq!(KittiesxModule::kitty_owners((COLLECTION_ID, 0)), Some(1));
+		assert_eq!(
+			KittiesxModule::owned_kitties(1).into_inner(),
+			vec![(COLLECTION_ID, 0), (COLLECTION_ID, 1)]
+		);
+		// no price was recorded before burning existed, so there is nothing to refund
+		assert_eq!(KittiesxModule::kitty_mint_prices((COLLECTION_ID, 0)), None);
+		assert_eq!(KittiesxModule::next_kitty_id(COLLECTION_ID), 2);
+		assert_eq!(KittiesxModule::next_collection_id(), 1);
+		let collection = KittiesxModule::collections(COLLECTION_ID).unwrap();
+		assert_eq!(collection.owner, KittyPalletId::get().into_account_truncating());
+		assert_eq!(collection.mint_price, KittyPledgePrice::get());
+
+		// already upgraded chains are left untouched
+		Migrations::<Test, KittyPledgePrice>::on_runtime_upgrade();
+		assert_eq!(
+			KittiesxModule::owned_kitties(1).into_inner(),
+			vec![(COLLECTION_ID, 0), (COLLECTION_ID, 1)]
+		);
+	});
+}
+
+#[test]
+fn migrate_v2_to_v4_builds_owned_kitties() {
+	new_test_ext().execute_with(|| {
+		// v2 had no `OwnedKitties` index, the re-key builds it from the owners
+		StorageVersion::new(2).put::<KittiesxModule>();
+		let kitty = Kitty { dna: [5u8; 16], name: *b"mimimimi", feature: *b"happy" };
+		for kitty_id in 0u32..3 {
+			v3::Kitties::<Test>::insert(kitty_id, kitty);
+			v3::KittyOwners::<Test>::insert(kitty_id, 1 + kitty_id as u64 % 2);
+		}
+		v3::NextKittyId::<Test>::put(3);
+		crate::Collections::<Test>::remove(COLLECTION_ID);
+
+		Migrations::<Test, KittyPledgePrice>::on_runtime_upgrade();
+		assert_eq!(KittiesxModule::on_chain_storage_version(), 4);
+		assert!(v3::OwnedKitties::<Test>::iter_keys().next().is_none());
+
+		run_migration();
+		assert_eq!(
+			KittiesxModule::owned_kitties(1).into_inner(),
+			vec![(COLLECTION_ID, 0), (COLLECTION_ID, 2)]
+		);
+		assert_eq!(KittiesxModule::owned_kitties(2).into_inner(), vec![(COLLECTION_ID, 1)]);
+		assert_eq!(KittiesxModule::kitties((COLLECTION_ID, 1)), Some(kitty));
+	});
+}
+
+#[test]
+fn migrate_v3_to_v4_rekeys_kitty_state() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<KittiesxModule>();
+		let kitty = Kitty { dna: [4u8; 16], name: *b"mimimimi", feature: *b"happy" };
+		for kitty_id in 0u32..3 {
+			v3::Kitties::<Test>::insert(kitty_id, kitty);
+			v3::KittyOwners::<Test>::insert(kitty_id, 1u64);
+		}
+		v3::OwnedKitties::<Test>::insert(1u64, BoundedVec::truncate_from(vec![0, 1, 2]));
+		unhashed::put(&old_kitty_key(KittyParents::<Test>::final_prefix(), 2), &(0u32, 1u32));
+		unhashed::put(&old_kitty_key(KittyOnSale::<Test>::final_prefix(), 1), &50u128);
+		unhashed::put(&old_kitty_key(KittyMintPrices::<Test>::final_prefix(), 0), &10u128);
+
+		MigrateV3ToV4::<Test, KittyPledgePrice>::on_runtime_upgrade();
+		assert_eq!(KittiesxModule::on_chain_storage_version(), 4);
+		assert_eq!(v3::Kitties::<Test>::iter_keys().count(), 3);
+
+		run_migration();
+		assert_eq!(KittiesxModule::kitties((COLLECTION_ID, 2)), Some(kitty));
+		assert_eq!(
+			KittiesxModule::kitty_parents((COLLECTION_ID, 2)),
+			Some(((COLLECTION_ID, 0), (COLLECTION_ID, 1)))
+		);
+		assert_eq!(KittiesxModule::kitty_on_sale((COLLECTION_ID, 1)), Some(50));
+		assert_eq!(KittiesxModule::kitty_mint_prices((COLLECTION_ID, 0)), Some(10));
+		assert_eq!(KittiesxModule::kitty_mint_prices((COLLECTION_ID, 2)), None);
+		assert_eq!(KittiesxModule::owned_kitties(1).len(), 3);
+		assert_eq!(v3::Kitties::<Test>::iter_keys().count(), 0);
+	});
+}
+
+#[test]
+fn burn_migrated_bred_kitty_refunds_nothing() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		// the creation price of the two v3 kitties that were created rather than bred
+		let pallet_account_id: u64 = KittyPalletId::get().into_account_truncating();
+		Balances::set_balance(&pallet_account_id, 20);
+		StorageVersion::new(3).put::<KittiesxModule>();
+		let kitty = Kitty { dna: [4u8; 16], name: *b"mimimimi", feature: *b"happy" };
+		for kitty_id in 0u32..3 {
+			v3::Kitties::<Test>::insert(kitty_id, kitty);
+			v3::KittyOwners::<Test>::insert(kitty_id, account_id);
+		}
+		v3::OwnedKitties::<Test>::insert(account_id, BoundedVec::truncate_from(vec![0, 1, 2]));
+		v3::NextKittyId::<Test>::put(3);
+		for kitty_id in 0u32..2 {
+			unhashed::put(
+				&old_kitty_key(KittyMintPrices::<Test>::final_prefix(), kitty_id),
+				&10u128,
+			);
+		}
+		unhashed::put(&old_kitty_key(KittyParents::<Test>::final_prefix(), 2), &(0u32, 1u32));
+
+		MigrateV3ToV4::<Test, KittyPledgePrice>::on_runtime_upgrade();
+		run_migration();
+		assert_eq!(KittiesxModule::kitty_mint_prices((COLLECTION_ID, 0)), Some(10));
+		assert_eq!(KittiesxModule::kitty_mint_prices((COLLECTION_ID, 2)), None);
+
+		assert_ok!(KittiesxModule::burn(RuntimeOrigin::signed(account_id), (COLLECTION_ID, 2)));
+		System::assert_last_event(
+			Event::KittyBurned { owner: account_id, kitty_id: (COLLECTION_ID, 2), refund: 0 }
+				.into(),
+		);
+		assert_eq!(Balances::free_balance(account_id), TEST_AMOUNT);
+		assert_eq!(Balances::free_balance(pallet_account_id), 20);
+
+		assert_ok!(KittiesxModule::burn(RuntimeOrigin::signed(account_id), (COLLECTION_ID, 0)));
+		System::assert_last_event(
+			Event::KittyBurned { owner: account_id, kitty_id: (COLLECTION_ID, 0), refund: 5 }
+				.into(),
+		);
+		assert_eq!(Balances::free_balance(pallet_account_id), 15);
+	});
+}
+
+#[test]
+fn migrate_v1_to_v2() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<KittiesxModule>();
+		let old = KittyV1 { dna: [3u8; 16], name: *b"abcd" };
+		unhashed::put(&v3::Kitties::<Test>::hashed_key_for(0), &old);
+
+		MigrateV0ToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(KittiesxModule::on_chain_storage_version(), 1);
+		MigrateV1ToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(KittiesxModule::on_chain_storage_version(), 2);
+		run_to_block(2);
+		assert_eq!(
+			v3::Kitties::<Test>::get(0),
+			Some(Kitty { dna: [3u8; 16], name: *b"abcd    ", feature: *b"happy" })
+		);
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn migrate_v0_to_v4_try_runtime() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<KittiesxModule>();
+		unhashed::put(&v3::Kitties::<Test>::hashed_key_for(0), &KittyV0([1u8; 16]));
+		v3::KittyOwners::<Test>::insert(0, 1u64);
+
+		assert_ok!(Migrations::<Test, KittyPledgePrice>::try_on_runtime_upgrade(true));
+		assert_eq!(KittiesxModule::on_chain_storage_version(), 4);
+		run_migration();
+		assert_eq!(KittiesxModule::kitty_owners((COLLECTION_ID, 0)), Some(1));
+	});
+}
+
+#[test]
+fn migrate_v3_to_v4_rekeys_in_batches() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		StorageVersion::new(3).put::<KittiesxModule>();
+		let kitty = Kitty { dna: [4u8; 16], name: *b"mimimimi", feature: *b"happy" };
+		for kitty_id in 0u32..5 {
+			v3::Kitties::<Test>::insert(kitty_id, kitty);
+			v3::KittyOwners::<Test>::insert(kitty_id, account_id);
+		}
+		v3::OwnedKitties::<Test>::insert(
+			account_id,
+			BoundedVec::truncate_from(vec![0, 1, 2, 3, 4]),
+		);
+		v3::NextKittyId::<Test>::put(5);
+
+		MigrateV3ToV4::<Test, KittyPledgePrice>::on_runtime_upgrade();
+		run_to_block(2);
+		assert_eq!(
+			KittiesxModule::ongoing_migration().unwrap().stage,
+			MigrationStage::Rekey { started_at: 1 }
+		);
+		assert!(KittiesxModule::owned_kitties(account_id).is_empty());
+		assert_noop!(
+			KittiesxModule::create(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				Perbill::zero()
+			),
+			Error::<Test>::MigrationOngoing
+		);
+
+		run_to_block(3);
+		System::assert_last_event(Event::MigrationProgressed { migrated: 2 }.into());
+		assert_eq!(KittiesxModule::kitties((COLLECTION_ID, 1)), Some(kitty));
+		assert_eq!(KittiesxModule::kitties((COLLECTION_ID, 2)), None);
+		assert_noop!(
+			KittiesxModule::transfer(RuntimeOrigin::signed(account_id), 2, (COLLECTION_ID, 0)),
+			Error::<Test>::MigrationOngoing
+		);
+
+		run_to_block(5);
+		System::assert_last_event(Event::MigrationProgressed { migrated: 5 }.into());
+		// every kitty is moved, only the queues of the skipped blocks are left
+		assert_eq!(
+			KittiesxModule::ongoing_migration().unwrap().stage,
+			MigrationStage::CatchUp { next: 1, until: 5 }
+		);
+		assert_eq!(KittiesxModule::owned_kitties(account_id).len(), 5);
+		assert_ok!(KittiesxModule::transfer(
+			RuntimeOrigin::signed(account_id),
+			2,
+			(COLLECTION_ID, 0)
+		));
+
+		run_to_block(9);
+		System::assert_last_event(Event::MigrationCompleted { migrated: 5 }.into());
+		assert_eq!(KittiesxModule::ongoing_migration(), None);
+		assert_eq!(v3::Kitties::<Test>::iter_keys().count(), 0);
+	});
+}
+
+#[test]
+fn migrate_v3_to_v4_settles_auction_due_while_rekeying() {
+	new_test_ext().execute_with(|| {
+		let (owner, bidder) = (1u64, 2u64);
+		Balances::set_balance(&bidder, TEST_AMOUNT);
+		assert_ok!(Balances::reserve(&bidder, 20));
+		StorageVersion::new(3).put::<KittiesxModule>();
+		let kitty = Kitty { dna: [4u8; 16], name: *b"mimimimi", feature: *b"happy" };
+		v3::Kitties::<Test>::insert(0, kitty);
+		v3::KittyOwners::<Test>::insert(0, owner);
+		v3::OwnedKitties::<Test>::insert(owner, BoundedVec::truncate_from(vec![0]));
+		v3::NextKittyId::<Test>::put(1);
+		let auction =
+			crate::Auction { owner, reserve_price: 5u128, end: 2u64, best_bid: Some((bidder, 20)) };
+		unhashed::put(&old_kitty_key(KittyAuctions::<Test>::final_prefix(), 0), &auction);
+		unhashed::put(&AuctionsEnding::<Test>::hashed_key_for(2), &vec![0u32]);
+
+		MigrateV3ToV4::<Test, KittyPledgePrice>::on_runtime_upgrade();
+		run_to_block(4);
+		// block 2 ended the auction while its kitty was still being moved
+		assert_eq!(KittiesxModule::kitty_owners((COLLECTION_ID, 0)), Some(owner));
+		assert!(KittiesxModule::kitty_auctions((COLLECTION_ID, 0)).is_some());
+
+		run_to_block(5);
+		System::assert_has_event(
+			Event::AuctionSettled {
+				owner,
+				winner: bidder,
+				kitty_id: (COLLECTION_ID, 0),
+				price: 20,
+			}
+			.into(),
+		);
+		System::assert_last_event(Event::MigrationCompleted { migrated: 1 }.into());
+		assert_eq!(KittiesxModule::kitty_owners((COLLECTION_ID, 0)), Some(bidder));
+		assert_eq!(Balances::free_balance(owner), 20);
+		assert_eq!(Balances::reserved_balance(bidder), 0);
+	});
+}
+
+#[test]
+fn lazy_migration_runs_in_batches() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<KittiesxModule>();
+		for kitty_id in 0u32..5 {
+			unhashed::put(
+				&v3::Kitties::<Test>::hashed_key_for(kitty_id),
+				&KittyV0([kitty_id as u8; 16]),
+			);
+			v3::KittyOwners::<Test>::insert(kitty_id, 1u64);
+		}
+
+		MigrateV0ToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(KittiesxModule::ongoing_migration().unwrap().migrated, 0);
+
+		run_to_block(2);
+		let state = KittiesxModule::ongoing_migration().unwrap();
+		assert_eq!(state.migrated, 2);
+		System::assert_last_event(Event::MigrationProgressed { migrated: 2 }.into());
+		let migrated = (0u32..5).filter(|id| v3::Kitties::<Test>::get(id).is_some()).count();
+		assert_eq!(migrated, 2);
+
+		run_to_block(3);
+		System::assert_last_event(Event::MigrationProgressed { migrated: 4 }.into());
+		run_to_block(4);
+		System::assert_last_event(Event::MigrationCompleted { migrated: 5 }.into());
+		assert_eq!(KittiesxModule::ongoing_migration(), None);
+		for kitty_id in 0u32..5 {
+			assert_eq!(v3::Kitties::<Test>::get(kitty_id).unwrap().dna, [kitty_id as u8; 16]);
+		}
+	});
+}
+
+#[test]
+fn lazy_migration_blocks_kitty_mutations() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		StorageVersion::new(1).put::<KittiesxModule>();
+		for old_id in 10u32..16 {
+			let old = KittyV1 { dna: [old_id as u8; 16], name: *b"abcd" };
+			unhashed::put(&v3::Kitties::<Test>::hashed_key_for(old_id), &old);
+		}
+		MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		assert_noop!(
+			KittiesxModule::create(
+				RuntimeOrigin::signed(account_id),
+				COLLECTION_ID,
+				Perbill::zero()
+			),
+			Error::<Test>::MigrationOngoing
+		);
+		assert_noop!(
+			KittiesxModule::set_name(
+				RuntimeOrigin::signed(account_id),
+				(COLLECTION_ID, 10),
+				BoundedVec::try_from(b"tom".to_vec()).unwrap()
+			),
+			Error::<Test>::MigrationOngoing
+		);
+		assert_noop!(
+			KittiesxModule::burn(RuntimeOrigin::signed(account_id), (COLLECTION_ID, 10)),
+			Error::<Test>::MigrationOngoing
+		);
+
+		run_to_block(5);
+		assert_eq!(KittiesxModule::ongoing_migration(), None);
+		for old_id in 10u32..16 {
+			assert_eq!(v3::Kitties::<Test>::get(old_id).unwrap().name, *b"abcd    ");
+		}
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+	});
+}
+
+fn indexed<V: codec::Decode + Default>(key: &[u8]) -> V {
+	StorageValueRef::persistent(key).get::<V>().unwrap().unwrap_or_default()
+}
+
+// runs the workers of block `n` the way the executive does, on top of `parent_hash`
+fn offchain_worker_at(n: u64, parent_hash: H256, hash: H256) {
+	System::initialize(&n, &parent_hash, &Default::default());
+	frame_system::BlockHash::<Test>::insert(n, hash);
+	KittiesxModule::offchain_worker(n);
+}
+
+#[test]
+fn offchain_worker_indexes_kitties() {
+	let mut ext = new_test_ext();
+	let (offchain, _state) = TestOffchainExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.execute_with(|| {
+		let (alice, bob, carol) = (1u64, 2u64, 3u64);
+		Balances::set_balance(&alice, TEST_AMOUNT);
+		Balances::set_balance(&carol, TEST_AMOUNT);
+		let kitty_0 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(alice),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(alice),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+
+		// the first run indexes every existing kitty
+		System::reset_events();
+		offchain_worker_at(1, H256::repeat_byte(0), H256::repeat_byte(1));
+		assert_eq!(
+			indexed::<Vec<CollectionKittyId>>(&index::owner_key(&alice)),
+			vec![kitty_0, kitty_1]
+		);
+		let traits = KittiesxModule::kitty_traits(kitty_0).unwrap();
+		for filter in index::TraitFilter::all(&traits) {
+			assert!(
+				indexed::<Vec<CollectionKittyId>>(&index::trait_key(&filter)).contains(&kitty_0)
+			);
+		}
+
+		System::set_block_number(2);
+		System::reset_events();
+		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(alice), kitty_0, 500));
+		assert_ok!(KittiesxModule::transfer(RuntimeOrigin::signed(alice), bob, kitty_1));
+		offchain_worker_at(2, H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_eq!(indexed::<Vec<CollectionKittyId>>(&index::owner_key(&alice)), vec![kitty_0]);
+		assert_eq!(indexed::<Vec<CollectionKittyId>>(&index::owner_key(&bob)), vec![kitty_1]);
+		assert_eq!(
+			indexed::<Vec<(CollectionKittyId, u128)>>(index::ON_SALE_KEY),
+			vec![(kitty_0, 500)]
+		);
+
+		System::set_block_number(3);
+		System::reset_events();
+		assert_ok!(KittiesxModule::buy(RuntimeOrigin::signed(carol), kitty_0, 500));
+		offchain_worker_at(3, H256::repeat_byte(2), H256::repeat_byte(3));
+		assert!(indexed::<Vec<CollectionKittyId>>(&index::owner_key(&alice)).is_empty());
+		assert_eq!(indexed::<Vec<CollectionKittyId>>(&index::owner_key(&carol)), vec![kitty_0]);
+		assert!(indexed::<Vec<(CollectionKittyId, u128)>>(index::ON_SALE_KEY).is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_resyncs_after_reorg_and_skipped_blocks() {
+	let mut ext = new_test_ext();
+	let (offchain, _state) = TestOffchainExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.execute_with(|| {
+		let (alice, bob) = (1u64, 2u64);
+		Balances::set_balance(&alice, TEST_AMOUNT);
+		let kitty_0 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(alice),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		System::reset_events();
+		offchain_worker_at(1, H256::repeat_byte(0), H256::repeat_byte(1));
+
+		// block 2a moves the kitty to bob, then 2b replaces it without that transfer
+		System::reset_events();
+		assert_ok!(KittiesxModule::transfer(RuntimeOrigin::signed(alice), bob, kitty_0));
+		offchain_worker_at(2, H256::repeat_byte(1), H256::repeat_byte(0x2a));
+		assert_eq!(indexed::<Vec<CollectionKittyId>>(&index::owner_key(&bob)), vec![kitty_0]);
+		assert_ok!(KittiesxModule::transfer(RuntimeOrigin::signed(bob), alice, kitty_0));
+		System::reset_events();
+
+		// 3b builds on 2b, whose worker never ran, and has no kitty events of its own
+		offchain_worker_at(3, H256::repeat_byte(0x2b), H256::repeat_byte(0x3b));
+		assert_eq!(indexed::<Vec<CollectionKittyId>>(&index::owner_key(&alice)), vec![kitty_0]);
+		assert!(indexed::<Vec<CollectionKittyId>>(&index::owner_key(&bob)).is_empty());
+
+		// kitties changed in blocks whose workers were skipped are picked up as well
+		let kitty_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(alice),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::burn(RuntimeOrigin::signed(alice), kitty_0));
+		System::reset_events();
+		offchain_worker_at(5, H256::repeat_byte(4), H256::repeat_byte(5));
+		assert_eq!(indexed::<Vec<CollectionKittyId>>(&index::owner_key(&alice)), vec![kitty_1]);
+		assert_eq!(indexed::<Vec<CollectionKittyId>>(index::KITTIES_KEY), vec![kitty_1]);
+	});
+}
+
+#[test]
+fn lent_kitty_breeds_only_with_borrower() {
+	new_test_ext().execute_with(|| {
+		let (owner, borrower) = (1u64, 2u64);
+		Balances::set_balance(&owner, TEST_AMOUNT);
+		Balances::set_balance(&borrower, TEST_AMOUNT);
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(owner),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_2 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(owner),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+
+		assert_noop!(
+			KittiesxModule::breed(
+				RuntimeOrigin::signed(borrower),
+				COLLECTION_ID,
+				kitty_id_1,
+				kitty_id_2,
+				Perbill::zero()
+			),
+			Error::<Test>::NotKittyUser
+		);
+
+		assert_ok!(KittiesxModule::lend(RuntimeOrigin::signed(owner), kitty_id_1, borrower, 10));
+		System::assert_last_event(
+			Event::KittyLent { owner, borrower, kitty_id: kitty_id_1, expires_at: 11 }.into(),
+		);
+		assert_ok!(KittiesxModule::lend(RuntimeOrigin::signed(owner), kitty_id_2, borrower, 10));
+		assert_eq!(KittiesxModule::kitty_user(kitty_id_1), Some(borrower));
+		assert_eq!(KittiesxModule::kitty_owners(kitty_id_1), Some(owner));
+
+		assert_noop!(
+			KittiesxModule::breed(
+				RuntimeOrigin::signed(owner),
+				COLLECTION_ID,
+				kitty_id_1,
+				kitty_id_2,
+				Perbill::zero()
+			),
+			Error::<Test>::NotKittyUser
+		);
+		let child_id = next_kitty_id();
+		assert_ok!(KittiesxModule::breed(
+			RuntimeOrigin::signed(borrower),
+			COLLECTION_ID,
+			kitty_id_1,
+			kitty_id_2,
+			Perbill::zero()
+		));
+		assert_eq!(KittiesxModule::kitty_owners(child_id), Some(borrower));
+	});
+}
+
+#[test]
+fn lent_kitty_cannot_change_hands() {
+	new_test_ext().execute_with(|| {
+		let (owner, borrower, other) = (1u64, 2u64, 3u64);
+		Balances::set_balance(&owner, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(owner),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::lend(RuntimeOrigin::signed(owner), kitty_id, borrower, 10));
+
+		assert_noop!(
+			KittiesxModule::transfer(RuntimeOrigin::signed(borrower), other, kitty_id),
+			Error::<Test>::ErrorKittyOwner
+		);
+		assert_noop!(
+			KittiesxModule::sale(RuntimeOrigin::signed(borrower), kitty_id, 100),
+			Error::<Test>::ErrorKittyOwner
+		);
+		assert_noop!(
+			KittiesxModule::transfer(RuntimeOrigin::signed(owner), other, kitty_id),
+			Error::<Test>::KittyLeased
+		);
+		assert_noop!(
+			KittiesxModule::sale(RuntimeOrigin::signed(owner), kitty_id, 100),
+			Error::<Test>::KittyLeased
+		);
+		assert_noop!(
+			KittiesxModule::create_auction(RuntimeOrigin::signed(owner), kitty_id, 100, 5),
+			Error::<Test>::KittyLeased
+		);
+		assert_noop!(
+			KittiesxModule::burn(RuntimeOrigin::signed(owner), kitty_id),
+			Error::<Test>::KittyLeased
+		);
+		assert_noop!(
+			KittiesxModule::lend(RuntimeOrigin::signed(owner), kitty_id, other, 10),
+			Error::<Test>::KittyLeased
+		);
+	});
+}
+
+#[test]
+fn lease_expires_on_initialize() {
+	new_test_ext().execute_with(|| {
+		let (owner, borrower, other) = (1u64, 2u64, 3u64);
+		Balances::set_balance(&owner, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(owner),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::lend(RuntimeOrigin::signed(owner), kitty_id, borrower, 3));
+		assert_eq!(KittiesxModule::leases_expiring(4).into_inner(), vec![kitty_id]);
+
+		run_to_block(3);
+		assert_eq!(KittiesxModule::kitty_user(kitty_id), Some(borrower));
+		run_to_block(4);
+		System::assert_last_event(Event::KittyReturned { owner, borrower, kitty_id }.into());
+		assert_eq!(KittiesxModule::kitty_leases(kitty_id), None);
+		assert_eq!(KittiesxModule::kitty_user(kitty_id), Some(owner));
+		assert_ok!(KittiesxModule::transfer(RuntimeOrigin::signed(owner), other, kitty_id));
+	});
+}
+
+#[test]
+fn borrower_returns_kitty_early() {
+	new_test_ext().execute_with(|| {
+		let (owner, borrower) = (1u64, 2u64);
+		Balances::set_balance(&owner, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(owner),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+
+		assert_noop!(
+			KittiesxModule::lend(RuntimeOrigin::signed(owner), kitty_id, borrower, 0),
+			Error::<Test>::InvalidLeaseDuration
+		);
+		assert_noop!(
+			KittiesxModule::lend(RuntimeOrigin::signed(owner), kitty_id, owner, 10),
+			Error::<Test>::ErrprRecipient
+		);
+		assert_noop!(
+			KittiesxModule::lend(RuntimeOrigin::signed(borrower), kitty_id, borrower, 10),
+			Error::<Test>::ErrorKittyOwner
+		);
+		assert_noop!(
+			KittiesxModule::return_kitty(RuntimeOrigin::signed(borrower), kitty_id),
+			Error::<Test>::KittyNotLeased
+		);
+
+		assert_ok!(KittiesxModule::lend(RuntimeOrigin::signed(owner), kitty_id, borrower, 10));
+		assert_noop!(
+			KittiesxModule::return_kitty(RuntimeOrigin::signed(owner), kitty_id),
+			Error::<Test>::NotKittyBorrower
+		);
+		assert_ok!(KittiesxModule::return_kitty(RuntimeOrigin::signed(borrower), kitty_id));
+		System::assert_last_event(Event::KittyReturned { owner, borrower, kitty_id }.into());
+		assert!(KittiesxModule::leases_expiring(11).is_empty());
+		assert_eq!(KittiesxModule::kitty_user(kitty_id), Some(owner));
+	});
+}
+
+#[test]
+fn genesis_mints_kitties_into_default_collection() {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	crate::GenesisConfig::<Test> {
+		mint_price: KittyPledgePrice::get(),
+		kitties: vec![
+			(1, [1; 16], *b"tom     "),
+			(2, [2; 16], *b"jerry   "),
+			(1, [3; 16], *b"spike   "),
+		],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		let collection = KittiesxModule::collections(COLLECTION_ID).unwrap();
+		assert_eq!(collection.owner, KittyPalletId::get().into_account_truncating());
+		assert_eq!(collection.mint_price, KittyPledgePrice::get());
+		assert_eq!(KittiesxModule::next_collection_id(), COLLECTION_ID + 1);
+		assert_eq!(KittiesxModule::next_kitty_id(COLLECTION_ID), 3);
+
+		let kitty = KittiesxModule::kitties((COLLECTION_ID, 1)).unwrap();
+		assert_eq!(kitty, Kitty { dna: [2; 16], name: *b"jerry   ", feature: *b"happy" });
+		assert_eq!(KittiesxModule::kitty_owners((COLLECTION_ID, 1)), Some(2));
+		assert_eq!(
+			KittiesxModule::owned_kitties(1).into_inner(),
+			vec![(COLLECTION_ID, 0), (COLLECTION_ID, 2)]
+		);
+
+		System::set_block_number(1);
+		Balances::set_balance(&1, TEST_AMOUNT);
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(1),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_eq!(KittiesxModule::kitty_owners((COLLECTION_ID, 3)), Some(1));
+	});
+}
+
+#[test]
+fn kitty_queries_page_through_results() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob) = (1u64, 2u64);
+		Balances::set_balance(&alice, TEST_AMOUNT);
+		let kitty_ids: Vec<_> = (0..5)
+			.map(|_| {
+				let kitty_id = next_kitty_id();
+				assert_ok!(KittiesxModule::create(
+					RuntimeOrigin::signed(alice),
+					COLLECTION_ID,
+					Perbill::zero()
+				));
+				kitty_id
+			})
+			.collect();
+		assert_eq!(KittiesxModule::kitties_by_owner(&alice, 0, 2), kitty_ids[..2].to_vec());
+		assert_eq!(KittiesxModule::kitties_by_owner(&alice, 4, 2), kitty_ids[4..].to_vec());
+		assert!(KittiesxModule::kitties_by_owner(&bob, 0, 2).is_empty());
+
+		for (i, kitty_id) in kitty_ids.iter().enumerate().skip(1) {
+			assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(alice), *kitty_id, i as u128));
+		}
+
+		let info = KittiesxModule::kitty_info(kitty_ids[1]).unwrap();
+		assert_eq!(info.owner, alice);
+		assert_eq!(info.price, Some(1));
+		assert_eq!(KittiesxModule::kitty_info(kitty_ids[0]).unwrap().price, None);
+		assert_eq!(KittiesxModule::kitty_info((COLLECTION_ID, 99)), None);
+
+		// listed kitties are held in escrow, yet still shown with their seller
+		assert_eq!(KittiesxModule::kitties_by_owner(&alice, 0, 2), kitty_ids[..1].to_vec());
+
+		let mut on_sale = Vec::new();
+		let mut cursor = None;
+		loop {
+			let page = KittiesxModule::kitties_on_sale(cursor, 3);
+			let Some((last, _)) = page.last() else { break };
+			cursor = Some(*last);
+			on_sale.extend(page);
+		}
+		on_sale.sort();
+		let expected: Vec<_> =
+			kitty_ids.iter().enumerate().skip(1).map(|(i, id)| (*id, i as u128)).collect();
+		assert_eq!(on_sale, expected);
+	});
+}
@@ -1,6 +1,7 @@
 use crate::{mock::*, Error, Event, Kitty};
 use frame_support::{assert_noop, assert_ok, traits::fungible::Mutate};
 use frame_system::Origin;
+use sp_runtime::Perbill;
 
 const TEST_AMOUNT: u128 = 10000;
 
@@ -9,8 +10,12 @@ fn create_kitty_with_event() {
 	new_test_ext().execute_with(|| {
 		let account_id = 1u64;
 		Balances::set_balance(&account_id, TEST_AMOUNT);
-		let kitty_id = KittiesxModule::next_kitty_id();
-		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(account_id)));
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
 		let kitty = KittiesxModule::kitties(kitty_id).unwrap();
 		let event = Event::KittyCreated { owner: account_id, kitty_id, kitty };
 		println!("{:?}", event);
@@ -23,15 +28,25 @@ fn breed_kitty_with_event() {
 	new_test_ext().execute_with(|| {
 		let account_id = 1u64;
 		Balances::set_balance(&account_id, TEST_AMOUNT);
-		let kitty_id_1 = KittiesxModule::next_kitty_id();
-		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(account_id)));
-		let kitty_id_2 = KittiesxModule::next_kitty_id();
-		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(account_id)));
-		let kitty_id = KittiesxModule::next_kitty_id();
+		let kitty_id_1 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id_2 = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let kitty_id = next_kitty_id();
 		assert_ok!(KittiesxModule::breed(
 			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
 			kitty_id_1,
-			kitty_id_2
+			kitty_id_2,
+			Perbill::zero()
 		));
 		let kitty = KittiesxModule::kitties(kitty_id).unwrap();
 		let event = Event::KittyBred { owner: account_id, kitty_id, kitty };
@@ -45,8 +60,12 @@ fn transfer_kitty_with_event() {
 	new_test_ext().execute_with(|| {
 		let account_id = 1u64;
 		Balances::set_balance(&account_id, TEST_AMOUNT);
-		let kitty_id = KittiesxModule::next_kitty_id();
-		assert_ok!(KittiesxModule::create(RuntimeOrigin::signed(account_id)));
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
 		let recipient_id = 2u64;
 		assert_ok!(KittiesxModule::transfer(
 			RuntimeOrigin::signed(account_id),
@@ -58,3 +77,78 @@ fn transfer_kitty_with_event() {
 		System::assert_last_event(event.into());
 	});
 }
+
+#[test]
+fn buy_kitty_with_event() {
+	new_test_ext().execute_with(|| {
+		let account_1_id = 1u64;
+		let account_2_id = 2u64;
+		Balances::set_balance(&account_1_id, TEST_AMOUNT);
+		Balances::set_balance(&account_2_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_1_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::sale(RuntimeOrigin::signed(account_1_id), kitty_id, 1000));
+		assert_ok!(KittiesxModule::buy(RuntimeOrigin::signed(account_2_id), kitty_id, 1000));
+		let event = Event::KittyBought {
+			owner: account_2_id,
+			seller: account_1_id,
+			kitty_id,
+			price: 1000,
+			fee: 50,
+			creator: None,
+			royalty: 0,
+		};
+		println!("{:?}", event);
+		System::assert_last_event(event.into());
+	});
+}
+
+#[test]
+fn set_name_kitty_with_event() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		let name = sp_runtime::BoundedVec::try_from(b"tom".to_vec()).unwrap();
+		assert_ok!(KittiesxModule::set_name(RuntimeOrigin::signed(account_id), kitty_id, name));
+		let event = Event::KittyNameSet { owner: account_id, kitty_id, name: *b"tom     " };
+		println!("{:?}", event);
+		System::assert_last_event(event.into());
+	});
+}
+
+#[test]
+fn approve_kitty_with_event() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+		Balances::set_balance(&account_id, TEST_AMOUNT);
+		let kitty_id = next_kitty_id();
+		assert_ok!(KittiesxModule::create(
+			RuntimeOrigin::signed(account_id),
+			COLLECTION_ID,
+			Perbill::zero()
+		));
+		assert_ok!(KittiesxModule::approve(RuntimeOrigin::signed(account_id), kitty_id, 2));
+		let event = Event::KittyApproval { owner: account_id, spender: 2, kitty_id };
+		println!("{:?}", event);
+		System::assert_last_event(event.into());
+
+		assert_ok!(KittiesxModule::set_approval_for_all(
+			RuntimeOrigin::signed(account_id),
+			3,
+			true
+		));
+		let event = Event::KittyApprovalForAll { owner: account_id, operator: 3, approved: true };
+		println!("{:?}", event);
+		System::assert_last_event(event.into());
+	});
+}
@@ -1,27 +1,1199 @@
+//! Weights for `pallet_kittiesx`.
+//!
+//! These values were NOT produced by a benchmark run. The storage accesses are read off each
+//! call, and the base times and the per-item slopes are hand estimates.
+//! Replace this file with real numbers by building the node with `--features
+//! runtime-benchmarks` and running:
+//!
+//! ./target/release/node-template benchmark pallet --chain dev --wasm-execution compiled \
+//!     --pallet pallet_kittiesx --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output ./pallets/kittiesx/src/weights.rs --template .maintain/frame-weight-template.hbs
+
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
+#![allow(missing_docs)]
 
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
+/// Weight functions needed for `pallet_kittiesx`.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
+	fn create() -> Weight;
+	fn breed() -> Weight;
+	fn transfer() -> Weight;
+	fn sale() -> Weight;
+	fn buy() -> Weight;
+	fn cancel_sale() -> Weight;
+	fn update_price() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn settle_auction() -> Weight;
+	fn create_with_name() -> Weight;
+	fn set_name() -> Weight;
+	fn set_feature() -> Weight;
+	fn burn() -> Weight;
+	fn approve() -> Weight;
+	fn set_approval_for_all() -> Weight;
+	fn transfer_from() -> Weight;
+	fn commit_create() -> Weight;
+	fn reveal_mint() -> Weight;
+	fn create_collection() -> Weight;
+	fn set_collection_metadata() -> Weight;
+	fn set_mint_price() -> Weight;
+	fn create_batch(c: u32) -> Weight;
+	fn transfer_batch(c: u32) -> Weight;
+	fn lend() -> Weight;
+	fn return_kitty() -> Weight;
+	fn end_lease() -> Weight;
+	fn cancel_approval() -> Weight;
 }
 
+/// Estimated weights for `pallet_kittiesx`, see the module docs.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	fn do_something() -> Weight {
-		Weight::from_parts(9_000_000, 0)
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::NextKittyId` (r:1 w:1)
+	/// Proof: `KittiesxModule::NextKittyId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Randomness::RandomMaterial` (r:1 w:0)
+	/// Proof: `Randomness::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:0 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Collections` (r:1 w:0)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyMintPrices` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyMintPrices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4415`
+		Weight::from_parts(59_867_000, 4415)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:2 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyParents` (r:2 w:1)
+	/// Proof: `KittiesxModule::KittyParents` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLastBred` (r:2 w:2)
+	/// Proof: `KittiesxModule::KittyLastBred` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::NextKittyId` (r:1 w:1)
+	/// Proof: `KittiesxModule::NextKittyId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Randomness::RandomMaterial` (r:1 w:0)
+	/// Proof: `Randomness::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyGenerations` (r:2 w:1)
+	/// Proof: `KittiesxModule::KittyGenerations` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:2 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:2 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Collections` (r:1 w:0)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyMintPrices` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyMintPrices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn breed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6977`
+		Weight::from_parts(61_580_000, 6977)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:0)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:2 w:2)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `7118`
+		Weight::from_parts(34_206_000, 7118)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:0)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:2 w:2)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittySellers` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittySellers` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn sale() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `8713`
+		Weight::from_parts(52_000_000, 8713)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittySellers` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittySellers` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:2 w:2)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:4 w:4)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn buy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `11252`
+		Weight::from_parts(98_000_000, 11252)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittySellers` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittySellers` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:2 w:2)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn cancel_sale() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `8713`
+		Weight::from_parts(48_000_000, 8713)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittySellers` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittySellers` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn update_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3598`
+		Weight::from_parts(16_000_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:0)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::AuctionsEnding` (r:1 w:1)
+	/// Proof: `KittiesxModule::AuctionsEnding` (`max_values`: None, `max_size`: Some(526), added: 3001, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3745`
+		Weight::from_parts(24_312_000, 3745)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6196`
+		Weight::from_parts(41_022_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:2 w:2)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn settle_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `8593`
+		Weight::from_parts(60_086_000, 8593)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::NextKittyId` (r:1 w:1)
+	/// Proof: `KittiesxModule::NextKittyId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Randomness::RandomMaterial` (r:1 w:0)
+	/// Proof: `Randomness::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:0 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Collections` (r:1 w:0)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyMintPrices` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyMintPrices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn create_with_name() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4415`
+		Weight::from_parts(60_530_000, 4415)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_name() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3593`
+		Weight::from_parts(31_558_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_feature() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3593`
+		Weight::from_parts(31_320_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLastBred` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyLastBred` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyMintPrices` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyMintPrices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn burn() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6236`
+		Weight::from_parts(64_256_000, 6236)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OperatorApprovals` (r:1 w:0)
+	/// Proof: `KittiesxModule::OperatorApprovals` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn approve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3556`
+		Weight::from_parts(15_103_000, 3556)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `KittiesxModule::OperatorApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::OperatorApprovals` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn set_approval_for_all() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(9_645_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:0)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OperatorApprovals` (r:1 w:0)
+	/// Proof: `KittiesxModule::OperatorApprovals` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:2 w:2)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn transfer_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `7118`
+		Weight::from_parts(36_268_000, 7118)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::NextKittyId` (r:1 w:1)
+	/// Proof: `KittiesxModule::NextKittyId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MintsRevealing` (r:1 w:1)
+	/// Proof: `KittiesxModule::MintsRevealing` (`max_values`: None, `max_size`: Some(526), added: 3001, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::PendingMints` (r:0 w:1)
+	/// Proof: `KittiesxModule::PendingMints` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Collections` (r:1 w:0)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn commit_create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6527`
+		Weight::from_parts(46_868_000, 6527)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `KittiesxModule::PendingMints` (r:1 w:1)
+	/// Proof: `KittiesxModule::PendingMints` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Randomness::RandomMaterial` (r:1 w:0)
+	/// Proof: `Randomness::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:0 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyMintPrices` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyMintPrices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn reveal_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4079`
+		Weight::from_parts(22_212_000, 4079)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `KittiesxModule::NextCollectionId` (r:1 w:1)
+	/// Proof: `KittiesxModule::NextCollectionId` (`max_values`: Some(1), `max_size`: Some(4), added: 2479, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Collections` (r:0 w:1)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	fn create_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `1489`
+		Weight::from_parts(13_482_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `KittiesxModule::Collections` (r:1 w:1)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	fn set_collection_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3814`
+		Weight::from_parts(14_217_000, 3814)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `KittiesxModule::Collections` (r:1 w:1)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	fn set_mint_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3814`
+		Weight::from_parts(13_655_000, 3814)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Collections` (r:1 w:0)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::NextKittyId` (r:1 w:1)
+	/// Proof: `KittiesxModule::NextKittyId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Randomness::RandomMaterial` (r:1 w:0)
+	/// Proof: `Randomness::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:0 w:64)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:0 w:64)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyMintPrices` (r:0 w:64)
+	/// Proof: `KittiesxModule::KittyMintPrices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:0 w:64)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 64]`.
+	fn create_batch(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6059`
+		Weight::from_parts(44_806_000, 6059)
+			.saturating_add(Weight::from_parts(23_028_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(c.into())))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:64 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:64 w:0)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:64 w:0)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:64 w:0)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:64 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:64 w:64)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:65 w:65)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:64)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:64 w:64)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:64 w:64)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 64]`.
+	fn transfer_batch(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6059`
+		Weight::from_parts(12_904_000, 6059)
+			.saturating_add(Weight::from_parts(32_816_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(c.into())))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::LeasesExpiring` (r:1 w:1)
+	/// Proof: `KittiesxModule::LeasesExpiring` (`max_values`: None, `max_size`: Some(526), added: 3001, mode: `MaxEncodedLen`)
+	fn lend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3745`
+		Weight::from_parts(22_579_000, 3745)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::LeasesExpiring` (r:1 w:1)
+	/// Proof: `KittiesxModule::LeasesExpiring` (`max_values`: None, `max_size`: Some(526), added: 3001, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn return_kitty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4487`
+		Weight::from_parts(20_415_000, 4487)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn end_lease() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3535`
+		Weight::from_parts(13_381_000, 3535)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OperatorApprovals` (r:1 w:0)
+	/// Proof: `KittiesxModule::OperatorApprovals` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn cancel_approval() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3556`
+		Weight::from_parts(15_500_000, 3556)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-
 }
 
+// For backwards compatibility and tests.
 impl WeightInfo for () {
-	fn do_something() -> Weight {
-		Weight::from_parts(9_000_000, 0)
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::NextKittyId` (r:1 w:1)
+	/// Proof: `KittiesxModule::NextKittyId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Randomness::RandomMaterial` (r:1 w:0)
+	/// Proof: `Randomness::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:0 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Collections` (r:1 w:0)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyMintPrices` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyMintPrices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4415`
+		Weight::from_parts(59_867_000, 4415)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:2 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyParents` (r:2 w:1)
+	/// Proof: `KittiesxModule::KittyParents` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLastBred` (r:2 w:2)
+	/// Proof: `KittiesxModule::KittyLastBred` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::NextKittyId` (r:1 w:1)
+	/// Proof: `KittiesxModule::NextKittyId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Randomness::RandomMaterial` (r:1 w:0)
+	/// Proof: `Randomness::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyGenerations` (r:2 w:1)
+	/// Proof: `KittiesxModule::KittyGenerations` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:2 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:2 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Collections` (r:1 w:0)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyMintPrices` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyMintPrices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn breed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6977`
+		Weight::from_parts(61_580_000, 6977)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:0)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:2 w:2)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `7118`
+		Weight::from_parts(34_206_000, 7118)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:0)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:2 w:2)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittySellers` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittySellers` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn sale() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `8713`
+		Weight::from_parts(52_000_000, 8713)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittySellers` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittySellers` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:2 w:2)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:4 w:4)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn buy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `11252`
+		Weight::from_parts(98_000_000, 11252)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittySellers` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittySellers` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:2 w:2)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn cancel_sale() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `8713`
+		Weight::from_parts(48_000_000, 8713)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittySellers` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittySellers` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn update_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3598`
+		Weight::from_parts(16_000_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:0)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::AuctionsEnding` (r:1 w:1)
+	/// Proof: `KittiesxModule::AuctionsEnding` (`max_values`: None, `max_size`: Some(526), added: 3001, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3745`
+		Weight::from_parts(24_312_000, 3745)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6196`
+		Weight::from_parts(41_022_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:2 w:2)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn settle_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `8593`
+		Weight::from_parts(60_086_000, 8593)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::NextKittyId` (r:1 w:1)
+	/// Proof: `KittiesxModule::NextKittyId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Randomness::RandomMaterial` (r:1 w:0)
+	/// Proof: `Randomness::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:0 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Collections` (r:1 w:0)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyMintPrices` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyMintPrices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn create_with_name() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4415`
+		Weight::from_parts(60_530_000, 4415)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_name() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3593`
+		Weight::from_parts(31_558_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_feature() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3593`
+		Weight::from_parts(31_320_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLastBred` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyLastBred` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyMintPrices` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyMintPrices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn burn() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6236`
+		Weight::from_parts(64_256_000, 6236)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OperatorApprovals` (r:1 w:0)
+	/// Proof: `KittiesxModule::OperatorApprovals` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn approve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3556`
+		Weight::from_parts(15_103_000, 3556)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `KittiesxModule::OperatorApprovals` (r:0 w:1)
+	/// Proof: `KittiesxModule::OperatorApprovals` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn set_approval_for_all() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(9_645_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:1 w:0)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OperatorApprovals` (r:1 w:0)
+	/// Proof: `KittiesxModule::OperatorApprovals` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:2 w:2)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:1 w:1)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn transfer_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `7118`
+		Weight::from_parts(36_268_000, 7118)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::NextKittyId` (r:1 w:1)
+	/// Proof: `KittiesxModule::NextKittyId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MintsRevealing` (r:1 w:1)
+	/// Proof: `KittiesxModule::MintsRevealing` (`max_values`: None, `max_size`: Some(526), added: 3001, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::PendingMints` (r:0 w:1)
+	/// Proof: `KittiesxModule::PendingMints` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Collections` (r:1 w:0)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn commit_create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6527`
+		Weight::from_parts(46_868_000, 6527)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `KittiesxModule::PendingMints` (r:1 w:1)
+	/// Proof: `KittiesxModule::PendingMints` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Randomness::RandomMaterial` (r:1 w:0)
+	/// Proof: `Randomness::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:0 w:1)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyMintPrices` (r:0 w:1)
+	/// Proof: `KittiesxModule::KittyMintPrices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn reveal_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4079`
+		Weight::from_parts(22_212_000, 4079)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `KittiesxModule::NextCollectionId` (r:1 w:1)
+	/// Proof: `KittiesxModule::NextCollectionId` (`max_values`: Some(1), `max_size`: Some(4), added: 2479, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Collections` (r:0 w:1)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	fn create_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `1489`
+		Weight::from_parts(13_482_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `KittiesxModule::Collections` (r:1 w:1)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	fn set_collection_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3814`
+		Weight::from_parts(14_217_000, 3814)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `KittiesxModule::Collections` (r:1 w:1)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	fn set_mint_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3814`
+		Weight::from_parts(13_655_000, 3814)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Collections` (r:1 w:0)
+	/// Proof: `KittiesxModule::Collections` (`max_values`: None, `max_size`: Some(331), added: 2806, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::NextKittyId` (r:1 w:1)
+	/// Proof: `KittiesxModule::NextKittyId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Randomness::RandomMaterial` (r:1 w:0)
+	/// Proof: `Randomness::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:1 w:1)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:0 w:64)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:0 w:64)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyMintPrices` (r:0 w:64)
+	/// Proof: `KittiesxModule::KittyMintPrices` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyCreators` (r:0 w:64)
+	/// Proof: `KittiesxModule::KittyCreators` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 64]`.
+	fn create_batch(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6059`
+		Weight::from_parts(44_806_000, 6059)
+			.saturating_add(Weight::from_parts(23_028_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(c.into())))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:64 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::Kitties` (r:64 w:0)
+	/// Proof: `KittiesxModule::Kitties` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:64 w:0)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:64 w:0)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:64 w:0)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:64 w:64)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OwnedKitties` (r:65 w:65)
+	/// Proof: `KittiesxModule::OwnedKitties` (`max_values`: None, `max_size`: Some(2098), added: 4573, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:0 w:64)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::MetadataDeposits` (r:64 w:64)
+	/// Proof: `KittiesxModule::MetadataDeposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:64 w:64)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 64]`.
+	fn transfer_batch(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6059`
+		Weight::from_parts(12_904_000, 6059)
+			.saturating_add(Weight::from_parts(32_816_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(c.into())))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOnSale` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOnSale` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyAuctions` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyAuctions` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::LeasesExpiring` (r:1 w:1)
+	/// Proof: `KittiesxModule::LeasesExpiring` (`max_values`: None, `max_size`: Some(526), added: 3001, mode: `MaxEncodedLen`)
+	fn lend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3745`
+		Weight::from_parts(22_579_000, 3745)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::LeasesExpiring` (r:1 w:1)
+	/// Proof: `KittiesxModule::LeasesExpiring` (`max_values`: None, `max_size`: Some(526), added: 3001, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn return_kitty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4487`
+		Weight::from_parts(20_415_000, 4487)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `KittiesxModule::KittyLeases` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyLeases` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn end_lease() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3535`
+		Weight::from_parts(13_381_000, 3535)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `KittiesxModule::OngoingMigration` (r:1 w:0)
+	/// Proof: `KittiesxModule::OngoingMigration` (`max_values`: Some(1), `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyOwners` (r:1 w:0)
+	/// Proof: `KittiesxModule::KittyOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::OperatorApprovals` (r:1 w:0)
+	/// Proof: `KittiesxModule::OperatorApprovals` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `KittiesxModule::KittyApprovals` (r:1 w:1)
+	/// Proof: `KittiesxModule::KittyApprovals` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn cancel_approval() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3556`
+		Weight::from_parts(15_500_000, 3556)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-
 }
@@ -0,0 +1,23 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_poex::{ClaimRecord, DocumentHash, DocumentProof, HashAlgorithm};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Version 2 added the claim provenance.
+	#[api_version(2)]
+	pub trait PoexApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// The proof of a document hash, if it was claimed with the same algorithm.
+		fn verify_document(
+			hash: DocumentHash,
+			algorithm: HashAlgorithm,
+		) -> Option<DocumentProof<AccountId, BlockNumber>>;
+		/// Every owner of a claim in order, the current one last; empty if it is not claimed.
+		fn claim_provenance(claim: Vec<u8>) -> Vec<ClaimRecord<AccountId, BlockNumber>>;
+	}
+}
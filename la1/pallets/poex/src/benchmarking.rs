@@ -4,11 +4,81 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as PoexModule;
+use codec::Encode;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{
+	traits::{Currency, Get, ReservableCurrency},
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::{Bounded, Zero};
 
 const SEED: u32 = 0;
 
+fn max_metadata<T: Config>() -> ClaimMetadataOf<T> {
+	ClaimMetadata {
+		mime_type: Some(BoundedVec::truncate_from(vec![
+			b'x';
+			T::MaxMimeTypeLength::get() as usize
+		])),
+		size: Some(u64::MAX),
+		label: Some(BoundedVec::truncate_from(vec![b'x'; T::MaxLabelLength::get() as usize])),
+	}
+}
+
+fn create_hash_claim_for<T: Config>(owner: &T::AccountId, hash: DocumentHash) {
+	let _ = PoexModule::<T>::create_hash_claim(
+		RawOrigin::Signed(owner.clone()).into(),
+		hash,
+		HashAlgorithm::Blake2_256,
+		max_metadata::<T>(),
+	);
+}
+
+/// Gives `claim` the longest history, so a transfer has to drop the oldest owner.
+fn fill_history<T: Config>(claim: &ClaimOf<T>) {
+	Proofs::<T>::mutate(claim, |proof| {
+		if let Some(proof) = proof {
+			for i in 0..T::MaxClaimHistory::get() {
+				let record = ClaimRecord {
+					owner: account("owner", i, SEED),
+					block_number: Default::default(),
+					action: ClaimAction::Transferred,
+				};
+				let _ = proof.history.try_push(record);
+			}
+		}
+	});
+}
+
+/// Fills the claims expiring at `expires_at` up to one below the limit.
+fn fill_expiries<T: Config>(expires_at: BlockNumberFor<T>) {
+	let claims = (1..T::MaxExpiriesPerBlock::get())
+		.map(|i| BoundedVec::truncate_from(i.encode()))
+		.collect::<Vec<_>>();
+	ExpiringClaims::<T>::insert(expires_at, BoundedVec::truncate_from(claims));
+}
+
+fn lifetime<T: Config>() -> BlockNumberFor<T> {
+	10u32.into()
+}
+
+fn expires_at<T: Config>() -> BlockNumberFor<T> {
+	frame_system::Pallet::<T>::block_number() + lifetime::<T>()
+}
+
+fn funded_account<T: Config>(name: &'static str) -> T::AccountId {
+	let who: T::AccountId = account(name, 0, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	who
+}
+
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	caller
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -18,43 +88,239 @@ mod benchmarks {
 	fn create_claim() {
 		let max = T::MaxClaimLength::get();
 		let claim = BoundedVec::try_from(vec![0; max as usize]).unwrap();
-		let caller: T::AccountId = whitelisted_caller();
+		let caller = funded_caller::<T>();
+		fill_expiries::<T>(expires_at::<T>());
 
 		#[extrinsic_call]
-		_(RawOrigin::Signed(caller), claim.clone());
+		_(RawOrigin::Signed(caller.clone()), claim.clone(), Some(lifetime::<T>()));
 
 		assert_eq!(Proofs::<T>::get(&claim).is_some(), true);
+		assert_eq!(ClaimExpiry::<T>::get(&claim), Some(expires_at::<T>()));
+		assert_eq!(
+			T::Currency::reserved_balance(&caller),
+			PoexModule::<T>::claim_deposit(max as usize)
+		);
 	}
 
 	#[benchmark]
 	fn revoke_claim() {
 		let max = T::MaxClaimLength::get();
 		let claim = BoundedVec::try_from(vec![0; max as usize]).unwrap();
-		let caller: T::AccountId = whitelisted_caller();
+		let caller = funded_caller::<T>();
 
+		fill_expiries::<T>(expires_at::<T>());
 		let c_claim = claim.clone();
-		let _ = PoexModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), c_claim);
+		let _ = PoexModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			c_claim,
+			Some(lifetime::<T>()),
+		);
 
 		#[extrinsic_call]
-		_(RawOrigin::Signed(caller), claim.clone());
+		_(RawOrigin::Signed(caller.clone()), claim.clone());
 
 		assert_eq!(Proofs::<T>::get(&claim).is_none(), true);
+		assert!(ExpiringClaims::<T>::get(expires_at::<T>()).iter().all(|c| *c != claim));
+		assert!(T::Currency::reserved_balance(&caller).is_zero());
 	}
 
 	#[benchmark]
 	fn transfer_claim() {
 		let max = T::MaxClaimLength::get();
 		let claim = BoundedVec::try_from(vec![0; max as usize]).unwrap();
-		let caller: T::AccountId = whitelisted_caller();
+		let caller = funded_caller::<T>();
 
 		let c_claim = claim.clone();
-		let _ = PoexModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), c_claim);
+		let _ =
+			PoexModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), c_claim, None);
+
+		// worst case: an earlier offer is dropped from its place in the expiry queue
+		let _ = PoexModule::<T>::offer_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			claim.clone(),
+			funded_account::<T>("earlier"),
+		);
+
+		let dest = funded_account::<T>("dest");
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), dest.clone(), claim.clone());
+
+		assert_eq!(ClaimOffers::<T>::get(&claim).map(|offer| offer.dest), Some(dest));
+		assert_eq!(Proofs::<T>::get(&claim).map(|proof| proof.owner), Some(caller));
+	}
+
+	#[benchmark]
+	fn create_hash_claim() {
+		let hash = [1; 32];
+		let caller = funded_caller::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), hash, HashAlgorithm::Sha2_256, max_metadata::<T>());
+
+		assert_eq!(
+			HashProofs::<T>::get(hash).map(|proof| proof.algorithm),
+			Some(HashAlgorithm::Sha2_256)
+		);
+	}
+
+	#[benchmark]
+	fn revoke_hash_claim() {
+		let hash = [1; 32];
+		let caller = funded_caller::<T>();
+		create_hash_claim_for::<T>(&caller, hash);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), hash);
+
+		assert_eq!(HashProofs::<T>::get(hash).is_none(), true);
+		assert!(T::Currency::reserved_balance(&caller).is_zero());
+	}
+
+	#[benchmark]
+	fn transfer_hash_claim() {
+		let hash = [1; 32];
+		let caller = funded_caller::<T>();
+		create_hash_claim_for::<T>(&caller, hash);
 
 		let dest: T::AccountId = account("dest", 0, SEED);
 		#[extrinsic_call]
-		_(RawOrigin::Signed(caller), dest, claim.clone());
+		_(RawOrigin::Signed(caller), dest.clone(), hash);
 
-		assert_eq!(Proofs::<T>::get(&claim).is_some(), true);
+		assert_eq!(HashProofs::<T>::get(hash).map(|proof| proof.owner), Some(dest));
+	}
+
+	#[benchmark]
+	fn renew_claim() {
+		let max = T::MaxClaimLength::get();
+		let claim = BoundedVec::try_from(vec![0; max as usize]).unwrap();
+		let caller = funded_caller::<T>();
+		let renewed_at = expires_at::<T>() + lifetime::<T>();
+
+		fill_expiries::<T>(expires_at::<T>());
+		fill_expiries::<T>(renewed_at);
+		let c_claim = claim.clone();
+		let _ = PoexModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			c_claim,
+			Some(lifetime::<T>()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), claim.clone(), lifetime::<T>());
+
+		assert_eq!(ClaimExpiry::<T>::get(&claim), Some(renewed_at));
+	}
+
+	#[benchmark]
+	fn expire_claim() {
+		let max = T::MaxClaimLength::get();
+		let claim: ClaimOf<T> = BoundedVec::try_from(vec![0; max as usize]).unwrap();
+		let caller = funded_caller::<T>();
+
+		let c_claim = claim.clone();
+		let _ = PoexModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			c_claim,
+			Some(lifetime::<T>()),
+		);
+
+		#[block]
+		{
+			PoexModule::<T>::expire_claim(claim.clone());
+		}
+
+		assert_eq!(Proofs::<T>::get(&claim).is_none(), true);
+		assert!(T::Currency::reserved_balance(&caller).is_zero());
+	}
+
+	#[benchmark]
+	fn offer_claim() {
+		let max = T::MaxClaimLength::get();
+		let claim: ClaimOf<T> = BoundedVec::try_from(vec![0; max as usize]).unwrap();
+		let caller = funded_caller::<T>();
+		let c_claim = claim.clone();
+		let _ =
+			PoexModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), c_claim, None);
+		let _ = PoexModule::<T>::offer_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			claim.clone(),
+			funded_account::<T>("earlier"),
+		);
+
+		let dest = funded_account::<T>("dest");
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), claim.clone(), dest.clone());
+
+		assert_eq!(ClaimOffers::<T>::get(&claim).map(|offer| offer.dest), Some(dest));
+	}
+
+	#[benchmark]
+	fn accept_claim() {
+		let max = T::MaxClaimLength::get();
+		let claim: ClaimOf<T> = BoundedVec::try_from(vec![0; max as usize]).unwrap();
+		let caller = funded_caller::<T>();
+		let c_claim = claim.clone();
+		let _ =
+			PoexModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), c_claim, None);
+		fill_history::<T>(&claim);
+
+		let dest = funded_account::<T>("dest");
+		let _ = PoexModule::<T>::offer_claim(
+			RawOrigin::Signed(caller).into(),
+			claim.clone(),
+			dest.clone(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(dest.clone()), claim.clone());
+
+		assert_eq!(Proofs::<T>::get(&claim).map(|proof| proof.owner), Some(dest.clone()));
+		assert_eq!(
+			T::Currency::reserved_balance(&dest),
+			PoexModule::<T>::claim_deposit(max as usize)
+		);
+	}
+
+	#[benchmark]
+	fn cancel_offer() {
+		let max = T::MaxClaimLength::get();
+		let claim: ClaimOf<T> = BoundedVec::try_from(vec![0; max as usize]).unwrap();
+		let caller = funded_caller::<T>();
+		let c_claim = claim.clone();
+		let _ =
+			PoexModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), c_claim, None);
+		let _ = PoexModule::<T>::offer_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			claim.clone(),
+			funded_account::<T>("dest"),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), claim.clone());
+
+		assert_eq!(ClaimOffers::<T>::get(&claim).is_none(), true);
+	}
+
+	#[benchmark]
+	fn expire_offer() {
+		let max = T::MaxClaimLength::get();
+		let claim: ClaimOf<T> = BoundedVec::try_from(vec![0; max as usize]).unwrap();
+		let caller = funded_caller::<T>();
+		let c_claim = claim.clone();
+		let _ =
+			PoexModule::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), c_claim, None);
+		let _ = PoexModule::<T>::offer_claim(
+			RawOrigin::Signed(caller).into(),
+			claim.clone(),
+			funded_account::<T>("dest"),
+		);
+
+		#[block]
+		{
+			PoexModule::<T>::expire_offer(claim.clone());
+		}
+
+		assert_eq!(ClaimOffers::<T>::get(&claim).is_none(), true);
 	}
 
 	impl_benchmark_test_suite!(PoexModule, crate::mock::new_test_ext(), crate::mock::Test);
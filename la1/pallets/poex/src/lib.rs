@@ -11,14 +11,117 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::{pallet, pallet_prelude::*, Blake2_128Concat};
+	use frame_support::{
+		dispatch::Vec,
+		pallet,
+		pallet_prelude::*,
+		traits::{BalanceStatus, Currency, ReservableCurrency, StorageVersion},
+		Blake2_128Concat,
+	};
 	use frame_system::pallet_prelude::{BlockNumberFor, *};
+	use sp_runtime::traits::{Saturating, Zero};
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type ClaimOf<T> = BoundedVec<u8, <T as Config>::MaxClaimLength>;
+
+	/// How an owner came to hold a claim.
+	#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub enum ClaimAction {
+		Created,
+		/// Pushed by the previous owner, before `transfer_claim` waited for the new one to accept.
+		Transferred,
+		/// Held since before ownership history was recorded.
+		Migrated,
+		/// Taken over by accepting an offer.
+		Accepted,
+	}
+
+	/// An owner of a claim, from the block it got the claim at.
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct ClaimRecord<AccountId, BlockNumber> {
+		pub owner: AccountId,
+		pub block_number: BlockNumber,
+		pub action: ClaimAction,
+	}
+
+	pub type ClaimRecordOf<T> =
+		ClaimRecord<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	/// A pending transfer of a claim, waiting for `dest` to accept it.
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct ClaimOffer<AccountId, BlockNumber> {
+		pub dest: AccountId,
+		/// First block the offer can no longer be accepted at.
+		pub expires_at: BlockNumber,
+	}
+
+	pub type ClaimOfferOf<T> =
+		ClaimOffer<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct Proof<AccountId, BlockNumber, History> {
+		pub owner: AccountId,
+		pub block_number: BlockNumber,
+		pub action: ClaimAction,
+		/// Previous owners, oldest first.
+		pub history: History,
+	}
+
+	pub type ProofOf<T> = Proof<
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T>,
+		BoundedVec<ClaimRecordOf<T>, <T as Config>::MaxClaimHistory>,
+	>;
+
+	/// Content hash a document is claimed by, instead of the document itself.
+	pub type DocumentHash = [u8; 32];
+
+	#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub enum HashAlgorithm {
+		Blake2_256,
+		Sha2_256,
+		Keccak256,
+	}
+
+	/// Optional description of a hashed document.
+	#[derive(
+		Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct ClaimMetadata<MimeType, Label> {
+		pub mime_type: Option<MimeType>,
+		/// Size of the document in bytes.
+		pub size: Option<u64>,
+		pub label: Option<Label>,
+	}
+
+	pub type ClaimMetadataOf<T> = ClaimMetadata<
+		BoundedVec<u8, <T as Config>::MaxMimeTypeLength>,
+		BoundedVec<u8, <T as Config>::MaxLabelLength>,
+	>;
+
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct HashProof<AccountId, BlockNumber, Metadata> {
+		pub owner: AccountId,
+		pub block_number: BlockNumber,
+		pub algorithm: HashAlgorithm,
+		pub metadata: Metadata,
+	}
+
+	pub type HashProofOf<T> =
+		HashProof<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, ClaimMetadataOf<T>>;
+
+	/// A hash proof as served to clients, with unbounded metadata.
+	pub type DocumentProof<AccountId, BlockNumber> =
+		HashProof<AccountId, BlockNumber, ClaimMetadata<Vec<u8>, Vec<u8>>>;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -26,26 +129,123 @@ pub mod pallet {
 		type MaxClaimLength: Get<u32>;
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type WeightInfo: WeightInfo;
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Reserved from the claimant for every claim.
+		#[pallet::constant]
+		type ClaimDepositBase: Get<BalanceOf<Self>>;
+		/// Reserved from the claimant for every byte of the claim, on top of the base.
+		#[pallet::constant]
+		type ClaimDepositPerByte: Get<BalanceOf<Self>>;
+		#[pallet::constant]
+		type MaxMimeTypeLength: Get<u32>;
+		#[pallet::constant]
+		type MaxLabelLength: Get<u32>;
+		/// Previous owners kept per claim; the oldest are dropped beyond this.
+		#[pallet::constant]
+		type MaxClaimHistory: Get<u32>;
+		/// Claims that can expire at the same block.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+		/// Blocks an offer of a claim can be accepted for.
+		#[pallet::constant]
+		type OfferLifetime: Get<BlockNumberFor<Self>>;
+		/// Offers that can expire at the same block, which is how many can be made in one.
+		#[pallet::constant]
+		type MaxOffersPerBlock: Get<u32>;
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
 	#[pallet::getter(fn proofs)]
-	pub type Proofs<T: Config> = StorageMap<
+	pub type Proofs<T: Config> = StorageMap<_, Blake2_128Concat, ClaimOf<T>, ProofOf<T>>;
+
+	/// Deposit reserved from the current owner of a claim. Claims created before deposits
+	/// were introduced have none.
+	#[pallet::storage]
+	#[pallet::getter(fn claim_deposits)]
+	pub type ClaimDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxClaimLength>, BalanceOf<T>>;
+
+	/// Block a claim created with a lifetime expires at.
+	#[pallet::storage]
+	#[pallet::getter(fn claim_expiry)]
+	pub type ClaimExpiry<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimOf<T>, BlockNumberFor<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn expiring_claims)]
+	pub type ExpiringClaims<T: Config> = StorageMap<
 		_,
-		Blake2_128Concat,
-		BoundedVec<u8, T::MaxClaimLength>,
-		(T::AccountId, BlockNumberFor<T>),
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ClaimOf<T>, T::MaxExpiriesPerBlock>,
+		ValueQuery,
 	>;
 
+	/// First block whose expiring claims and offers have not all been swept yet. Unset until
+	/// the first claim with a lifetime is created or the first offer is made.
+	#[pallet::storage]
+	#[pallet::getter(fn sweep_cursor)]
+	pub type SweepCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Offers are dropped with the next offer, whenever the claim changes hands or goes away,
+	/// and by `on_idle` once they expire.
+	#[pallet::storage]
+	#[pallet::getter(fn claim_offers)]
+	pub type ClaimOffers<T: Config> = StorageMap<_, Blake2_128Concat, ClaimOf<T>, ClaimOfferOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn expiring_offers)]
+	pub type ExpiringOffers<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ClaimOf<T>, T::MaxOffersPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn hash_proofs)]
+	pub type HashProofs<T: Config> = StorageMap<_, Blake2_128Concat, DocumentHash, HashProofOf<T>>;
+
+	/// Deposit reserved from the current owner of a hash claim.
+	#[pallet::storage]
+	#[pallet::getter(fn hash_claim_deposits)]
+	pub type HashClaimDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, DocumentHash, BalanceOf<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		ClaimCreated(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
 		ClaimRevoked(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
-		ClaimTransfer(T::AccountId, T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
+		// index 2 was `ClaimTransfer`, which went away when `transfer_claim` became an offer
+		#[codec(index = 3)]
+		HashClaimCreated(T::AccountId, DocumentHash, HashAlgorithm),
+		#[codec(index = 4)]
+		HashClaimRevoked(T::AccountId, DocumentHash),
+		#[codec(index = 5)]
+		HashClaimTransfer(T::AccountId, T::AccountId, DocumentHash),
+		#[codec(index = 6)]
+		ClaimRenewed(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, BlockNumberFor<T>),
+		#[codec(index = 7)]
+		ClaimExpired(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
+		#[codec(index = 8)]
+		ClaimOffered(
+			T::AccountId,
+			T::AccountId,
+			BoundedVec<u8, T::MaxClaimLength>,
+			BlockNumberFor<T>,
+		),
+		#[codec(index = 9)]
+		ClaimAccepted(T::AccountId, T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
+		#[codec(index = 10)]
+		OfferCancelled(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
 	}
 
 	#[pallet::error]
@@ -56,10 +256,23 @@ pub mod pallet {
 		ErrorOwner,
 		SameOwner,
 		NoDest,
+		NotEnoughCurrency,
+		InvalidLifetime,
+		NotExpiring,
+		ClaimExpired,
+		TooManyExpiries,
+		NoOffer,
+		NotOfferDest,
+		OfferExpired,
+		TooManyOffers,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_expired(n, remaining_weight)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -68,15 +281,30 @@ pub mod pallet {
 		pub fn create_claim(
 			origin: OriginFor<T>,
 			claim: BoundedVec<u8, T::MaxClaimLength>,
+			lifetime: Option<BlockNumberFor<T>>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
 			ensure!(!Proofs::<T>::contains_key(&claim), Error::<T>::AlreadyExist);
 
-			Proofs::<T>::insert(
-				&claim,
-				(sender.clone(), frame_system::Pallet::<T>::block_number()),
-			);
+			if let Some(lifetime) = lifetime {
+				ensure!(!lifetime.is_zero(), Error::<T>::InvalidLifetime);
+				let now = frame_system::Pallet::<T>::block_number();
+				Self::schedule_expiry(&claim, now.saturating_add(lifetime))?;
+			}
+
+			let deposit = Self::reserve_deposit(&sender, claim.len())?;
+			if !deposit.is_zero() {
+				ClaimDeposits::<T>::insert(&claim, deposit);
+			}
+
+			let proof = Proof {
+				owner: sender.clone(),
+				block_number: frame_system::Pallet::<T>::block_number(),
+				action: ClaimAction::Created,
+				history: Default::default(),
+			};
+			Proofs::<T>::insert(&claim, proof);
 
 			Self::deposit_event(Event::ClaimCreated(sender, claim));
 			Ok(().into())
@@ -90,15 +318,22 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			let (owner, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::NotExist)?;
-			ensure!(owner == sender, Error::<T>::ErrorOwner);
+			let proof = Proofs::<T>::get(&claim).ok_or(Error::<T>::NotExist)?;
+			ensure!(proof.owner == sender, Error::<T>::ErrorOwner);
 
 			Proofs::<T>::remove(&claim);
+			Self::drop_offer(&claim);
+			Self::unschedule_expiry(&claim);
+			if let Some(deposit) = ClaimDeposits::<T>::take(&claim) {
+				T::Currency::unreserve(&sender, deposit);
+			}
 
 			Self::deposit_event(Event::ClaimRevoked(sender, claim));
 			Ok(())
 		}
 
+		/// Offers a claim to an existing account, the same as `offer_claim`. Nothing changes
+		/// hands until `dest` accepts it with `accept_claim`.
 		#[pallet::call_index(2)]
 		#[pallet::weight(T::WeightInfo::transfer_claim())]
 		pub fn transfer_claim(
@@ -108,20 +343,374 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
+			Self::offer(sender, claim, dest)?;
+			Ok(().into())
+		}
+
+		/// Claims a document by its content hash, computed off chain with `algorithm`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::create_hash_claim())]
+		pub fn create_hash_claim(
+			origin: OriginFor<T>,
+			hash: DocumentHash,
+			algorithm: HashAlgorithm,
+			metadata: ClaimMetadataOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(!HashProofs::<T>::contains_key(hash), Error::<T>::AlreadyExist);
+
+			let deposit = Self::reserve_deposit(&sender, hash.len() + metadata.encoded_size())?;
+			if !deposit.is_zero() {
+				HashClaimDeposits::<T>::insert(hash, deposit);
+			}
+
+			let proof = HashProof {
+				owner: sender.clone(),
+				block_number: frame_system::Pallet::<T>::block_number(),
+				algorithm,
+				metadata,
+			};
+			HashProofs::<T>::insert(hash, proof);
+
+			Self::deposit_event(Event::HashClaimCreated(sender, hash, algorithm));
+			Ok(())
+		}
+
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::revoke_hash_claim())]
+		pub fn revoke_hash_claim(origin: OriginFor<T>, hash: DocumentHash) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let proof = HashProofs::<T>::get(hash).ok_or(Error::<T>::NotExist)?;
+			ensure!(proof.owner == sender, Error::<T>::ErrorOwner);
+
+			HashProofs::<T>::remove(hash);
+			if let Some(deposit) = HashClaimDeposits::<T>::take(hash) {
+				T::Currency::unreserve(&sender, deposit);
+			}
+
+			Self::deposit_event(Event::HashClaimRevoked(sender, hash));
+			Ok(())
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::transfer_hash_claim())]
+		pub fn transfer_hash_claim(
+			origin: OriginFor<T>,
+			dest: T::AccountId,
+			hash: DocumentHash,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
 			ensure!(sender != dest, Error::<T>::SameOwner);
 
-			//let has = frame_system::Pallet::<T>::account_exists(&dest);
-			//ensure!(has, Error::<T>::NoDest);
+			let mut proof = HashProofs::<T>::get(hash).ok_or(Error::<T>::NotExist)?;
+			ensure!(proof.owner == sender, Error::<T>::ErrorOwner);
 
-			let (owner, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::NotExist)?;
-			ensure!(owner == sender, Error::<T>::ErrorOwner);
+			if let Some(deposit) = HashClaimDeposits::<T>::get(hash) {
+				HashClaimDeposits::<T>::insert(hash, Self::move_deposit(&sender, &dest, deposit)?);
+			}
 
-			Proofs::<T>::mutate(&claim, |v| {
-				*v = Some((dest.clone(), frame_system::Pallet::<T>::block_number()))
-			});
+			proof.owner = dest.clone();
+			proof.block_number = frame_system::Pallet::<T>::block_number();
+			HashProofs::<T>::insert(hash, proof);
 
-			Self::deposit_event(Event::ClaimTransfer(sender, dest, claim));
-			Ok(().into())
+			Self::deposit_event(Event::HashClaimTransfer(sender, dest, hash));
+			Ok(())
+		}
+
+		/// Pushes the expiry of a claim created with a lifetime back by `lifetime` blocks.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::renew_claim())]
+		pub fn renew_claim(
+			origin: OriginFor<T>,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+			lifetime: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let proof = Proofs::<T>::get(&claim).ok_or(Error::<T>::NotExist)?;
+			ensure!(proof.owner == sender, Error::<T>::ErrorOwner);
+			ensure!(!lifetime.is_zero(), Error::<T>::InvalidLifetime);
+
+			let expires_at = ClaimExpiry::<T>::get(&claim).ok_or(Error::<T>::NotExpiring)?;
+			// expired claims wait for `on_idle` to sweep them and cannot come back
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(expires_at > now, Error::<T>::ClaimExpired);
+
+			let expires_at = expires_at.saturating_add(lifetime);
+			Self::unschedule_expiry(&claim);
+			Self::schedule_expiry(&claim, expires_at)?;
+
+			Self::deposit_event(Event::ClaimRenewed(sender, claim, expires_at));
+			Ok(())
+		}
+
+		/// Offers a claim to an existing account `dest`, who has `OfferLifetime` blocks to accept
+		/// it. Replaces any earlier offer of the claim.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::offer_claim())]
+		pub fn offer_claim(
+			origin: OriginFor<T>,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::offer(sender, claim, dest)
+		}
+
+		/// Takes over a claim offered to the caller, together with its deposit.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::accept_claim())]
+		pub fn accept_claim(
+			origin: OriginFor<T>,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let offer = ClaimOffers::<T>::get(&claim).ok_or(Error::<T>::NoOffer)?;
+			ensure!(offer.dest == sender, Error::<T>::NotOfferDest);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < offer.expires_at, Error::<T>::OfferExpired);
+
+			// offers go away with the claim, so the one who made it still owns it
+			let proof = Proofs::<T>::get(&claim).ok_or(Error::<T>::NotExist)?;
+			Self::ensure_not_expired(&claim)?;
+			let owner = proof.owner.clone();
+			Self::move_claim(&claim, proof, &sender, ClaimAction::Accepted)?;
+
+			Self::deposit_event(Event::ClaimAccepted(owner, sender, claim));
+			Ok(())
+		}
+
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::cancel_offer())]
+		pub fn cancel_offer(
+			origin: OriginFor<T>,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let proof = Proofs::<T>::get(&claim).ok_or(Error::<T>::NotExist)?;
+			ensure!(proof.owner == sender, Error::<T>::ErrorOwner);
+			ensure!(Self::drop_offer(&claim), Error::<T>::NoOffer);
+
+			Self::deposit_event(Event::OfferCancelled(sender, claim));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Deposit reserved for a claim of `len` bytes.
+		pub fn claim_deposit(len: usize) -> BalanceOf<T> {
+			let per_byte = T::ClaimDepositPerByte::get().saturating_mul((len as u32).into());
+			T::ClaimDepositBase::get().saturating_add(per_byte)
+		}
+
+		/// Every owner of `claim` in order, the current one last.
+		pub fn claim_provenance(claim: &[u8]) -> Vec<ClaimRecordOf<T>> {
+			let Ok(claim) = ClaimOf::<T>::try_from(claim.to_vec()) else { return Vec::new() };
+			let Some(proof) = Self::proofs(claim) else { return Vec::new() };
+			let current = ClaimRecord {
+				owner: proof.owner,
+				block_number: proof.block_number,
+				action: proof.action,
+			};
+			let mut chain = proof.history.into_inner();
+			chain.push(current);
+			chain
+		}
+
+		/// The proof of `hash` if it was claimed with `algorithm`.
+		pub fn verify_document(
+			hash: DocumentHash,
+			algorithm: HashAlgorithm,
+		) -> Option<DocumentProof<T::AccountId, BlockNumberFor<T>>> {
+			let proof = Self::hash_proofs(hash).filter(|proof| proof.algorithm == algorithm)?;
+			let metadata = ClaimMetadata {
+				mime_type: proof.metadata.mime_type.map(Into::into),
+				size: proof.metadata.size,
+				label: proof.metadata.label.map(Into::into),
+			};
+			Some(HashProof {
+				owner: proof.owner,
+				block_number: proof.block_number,
+				algorithm: proof.algorithm,
+				metadata,
+			})
+		}
+
+		fn schedule_expiry(claim: &ClaimOf<T>, expires_at: BlockNumberFor<T>) -> DispatchResult {
+			ExpiringClaims::<T>::try_mutate(expires_at, |claims| claims.try_push(claim.clone()))
+				.map_err(|_| Error::<T>::TooManyExpiries)?;
+			ClaimExpiry::<T>::insert(claim, expires_at);
+			if !SweepCursor::<T>::exists() {
+				SweepCursor::<T>::put(frame_system::Pallet::<T>::block_number());
+			}
+			Ok(())
+		}
+
+		fn offer(sender: T::AccountId, claim: ClaimOf<T>, dest: T::AccountId) -> DispatchResult {
+			ensure!(sender != dest, Error::<T>::SameOwner);
+			let has = frame_system::Pallet::<T>::account_exists(&dest);
+			ensure!(has, Error::<T>::NoDest);
+
+			let proof = Proofs::<T>::get(&claim).ok_or(Error::<T>::NotExist)?;
+			ensure!(proof.owner == sender, Error::<T>::ErrorOwner);
+			Self::ensure_not_expired(&claim)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let expires_at = now.saturating_add(T::OfferLifetime::get());
+			Self::drop_offer(&claim);
+			ExpiringOffers::<T>::try_mutate(expires_at, |claims| claims.try_push(claim.clone()))
+				.map_err(|_| Error::<T>::TooManyOffers)?;
+			ClaimOffers::<T>::insert(&claim, ClaimOffer { dest: dest.clone(), expires_at });
+			if !SweepCursor::<T>::exists() {
+				SweepCursor::<T>::put(now);
+			}
+
+			Self::deposit_event(Event::ClaimOffered(sender, dest, claim, expires_at));
+			Ok(())
+		}
+
+		/// Expired claims stay in storage until `on_idle` sweeps them, but cannot change hands.
+		fn ensure_not_expired(claim: &ClaimOf<T>) -> DispatchResult {
+			if let Some(expires_at) = ClaimExpiry::<T>::get(claim) {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(expires_at > now, Error::<T>::ClaimExpired);
+			}
+			Ok(())
+		}
+
+		/// Removes the pending offer of `claim` with its place in the expiry queue, and returns
+		/// whether there was one.
+		fn drop_offer(claim: &ClaimOf<T>) -> bool {
+			let Some(offer) = ClaimOffers::<T>::take(claim) else { return false };
+			ExpiringOffers::<T>::mutate(offer.expires_at, |claims| claims.retain(|c| c != claim));
+			true
+		}
+
+		fn unschedule_expiry(claim: &ClaimOf<T>) {
+			if let Some(expires_at) = ClaimExpiry::<T>::take(claim) {
+				ExpiringClaims::<T>::mutate(expires_at, |claims| claims.retain(|c| c != claim));
+			}
+		}
+
+		/// Sweeps the claims and offers expired up to block `n` in order of expiry, as far as
+		/// `limit` allows, and returns the weight used.
+		pub(crate) fn sweep_expired(n: BlockNumberFor<T>, limit: Weight) -> Weight {
+			let db = T::DbWeight::get();
+			let mut used = db.reads_writes(1, 1);
+			if used.any_gt(limit) {
+				return Weight::zero()
+			}
+			let Some(mut cursor) = SweepCursor::<T>::get() else { return db.reads(1) };
+
+			let per_block = db.reads_writes(2, 2);
+			let per_claim = T::WeightInfo::expire_claim();
+			let per_offer = T::WeightInfo::expire_offer();
+			while cursor <= n && !used.saturating_add(per_block).any_gt(limit) {
+				used.saturating_accrue(per_block);
+				let mut claims = ExpiringClaims::<T>::get(cursor).into_inner();
+				while !claims.is_empty() && !used.saturating_add(per_claim).any_gt(limit) {
+					used.saturating_accrue(per_claim);
+					if let Some(claim) = claims.pop() {
+						Self::expire_claim(claim);
+					}
+				}
+				// expiring claims drop their offers, so the offers left are swept after them
+				let mut offers = ExpiringOffers::<T>::get(cursor).into_inner();
+				while claims.is_empty()
+					&& !offers.is_empty()
+					&& !used.saturating_add(per_offer).any_gt(limit)
+				{
+					used.saturating_accrue(per_offer);
+					if let Some(claim) = offers.pop() {
+						Self::expire_offer(claim);
+					}
+				}
+				if !claims.is_empty() || !offers.is_empty() {
+					ExpiringClaims::<T>::insert(cursor, BoundedVec::truncate_from(claims));
+					ExpiringOffers::<T>::insert(cursor, BoundedVec::truncate_from(offers));
+					break;
+				}
+				ExpiringClaims::<T>::remove(cursor);
+				ExpiringOffers::<T>::remove(cursor);
+				cursor.saturating_inc();
+			}
+			SweepCursor::<T>::put(cursor);
+			used
+		}
+
+		pub(crate) fn expire_claim(claim: ClaimOf<T>) {
+			ClaimExpiry::<T>::remove(&claim);
+			Self::drop_offer(&claim);
+			let Some(proof) = Proofs::<T>::take(&claim) else { return };
+			if let Some(deposit) = ClaimDeposits::<T>::take(&claim) {
+				T::Currency::unreserve(&proof.owner, deposit);
+			}
+			Self::deposit_event(Event::ClaimExpired(proof.owner, claim));
+		}
+
+		/// Drops an offer nobody accepted in time. Offers that were replaced, accepted or
+		/// cancelled already left the queue.
+		pub(crate) fn expire_offer(claim: ClaimOf<T>) {
+			ClaimOffers::<T>::remove(&claim);
+		}
+
+		/// Gives `claim` and its deposit to `dest`, dropping any pending offer.
+		fn move_claim(
+			claim: &ClaimOf<T>,
+			mut proof: ProofOf<T>,
+			dest: &T::AccountId,
+			action: ClaimAction,
+		) -> DispatchResult {
+			if let Some(deposit) = ClaimDeposits::<T>::get(claim) {
+				ClaimDeposits::<T>::insert(claim, Self::move_deposit(&proof.owner, dest, deposit)?);
+			}
+			Self::drop_offer(claim);
+			Self::hand_over(&mut proof, dest.clone(), action);
+			Proofs::<T>::insert(claim, proof);
+			Ok(())
+		}
+
+		/// Moves `proof` to `dest`, keeping the current owner in its history.
+		fn hand_over(proof: &mut ProofOf<T>, dest: T::AccountId, action: ClaimAction) {
+			let previous = ClaimRecord {
+				owner: core::mem::replace(&mut proof.owner, dest),
+				block_number: core::mem::replace(
+					&mut proof.block_number,
+					frame_system::Pallet::<T>::block_number(),
+				),
+				action: core::mem::replace(&mut proof.action, action),
+			};
+			if proof.history.is_full() && !proof.history.is_empty() {
+				proof.history.remove(0);
+			}
+			// only fails when `MaxClaimHistory` is zero
+			let _ = proof.history.try_push(previous);
+		}
+
+		fn reserve_deposit(who: &T::AccountId, len: usize) -> Result<BalanceOf<T>, DispatchError> {
+			let deposit = Self::claim_deposit(len);
+			if !deposit.is_zero() {
+				T::Currency::reserve(who, deposit).map_err(|_| Error::<T>::NotEnoughCurrency)?;
+			}
+			Ok(deposit)
+		}
+
+		/// Moves a claim's deposit to its new owner and returns what the new owner holds.
+		fn move_deposit(
+			owner: &T::AccountId,
+			dest: &T::AccountId,
+			deposit: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let missing =
+				T::Currency::repatriate_reserved(owner, dest, deposit, BalanceStatus::Reserved)?;
+			// the new owner only holds what was still reserved from the previous one
+			Ok(deposit.saturating_sub(missing))
 		}
 	}
 }
@@ -0,0 +1,4 @@
+pub mod v1;
+
+/// Every poex migration in order, for the runtime's `Executive`.
+pub type Migrations<T> = (v1::MigrateV0ToV1<T>,);
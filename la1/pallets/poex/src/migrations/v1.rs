@@ -0,0 +1,76 @@
+use core::marker::PhantomData;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::dispatch::Vec;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+use crate::{ClaimAction, ClaimOf, Pallet, Proof, Proofs};
+
+/// Layout of `Proofs` before ownership history: the owner and the block it got the claim at.
+#[frame_support::storage_alias]
+pub type ProofsV0<T: crate::Config> = StorageMap<
+	Pallet<T>,
+	Blake2_128Concat,
+	ClaimOf<T>,
+	(<T as frame_system::Config>::AccountId, BlockNumberFor<T>),
+>;
+
+/// Rewrites the `(owner, block)` proofs of storage version 0 with an empty history.
+pub struct MigrateV0ToV1<T>(PhantomData<T>);
+
+impl<T: crate::Config> OnRuntimeUpgrade for MigrateV0ToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		let current_version = Pallet::<T>::current_storage_version();
+
+		if on_chain_version != 0 || current_version < 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut count = 0u64;
+		Proofs::<T>::translate::<(T::AccountId, BlockNumberFor<T>), _>(
+			|_, (owner, block_number)| {
+				count += 1;
+				Some(Proof {
+					owner,
+					block_number,
+					action: ClaimAction::Migrated,
+					history: Default::default(),
+				})
+			},
+		);
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		log::info!("=== poex migration v0 -> v1 === {} proofs", count);
+
+		T::DbWeight::get().reads_writes(count + 1, count + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		if Pallet::<T>::on_chain_storage_version() != 0 {
+			return Ok(Vec::new());
+		}
+		let count = ProofsV0::<T>::iter_keys().count() as u32;
+		ensure!(ProofsV0::<T>::iter().count() as u32 == count, "some proofs do not decode as v0");
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		if state.is_empty() {
+			return Ok(());
+		}
+		let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre_upgrade state")?;
+		ensure!(Proofs::<T>::iter().count() as u32 == count, "some proofs were lost");
+		ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not bumped");
+		Ok(())
+	}
+}
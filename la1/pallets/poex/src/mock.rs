@@ -1,12 +1,18 @@
 use crate as pallet_poex;
 use frame_support::traits::{ConstU16, ConstU32, ConstU64};
-use sp_core::H256;
+use sp_core::{ConstU128, H256};
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
+type Balance = u128;
+
+pub const CLAIM_DEPOSIT_BASE: Balance = 10;
+pub const CLAIM_DEPOSIT_PER_BYTE: Balance = 2;
+/// Free balance of accounts 1 to 3 at genesis.
+pub const INITIAL_BALANCE: Balance = 100;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -14,6 +20,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		PoexModule: pallet_poex,
+		Balances: pallet_balances,
 	}
 );
 
@@ -34,7 +41,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -43,13 +50,44 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
+}
+
 impl pallet_poex::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxClaimLength = ConstU32<10>;
+	type Currency = Balances;
+	type ClaimDepositBase = ConstU128<CLAIM_DEPOSIT_BASE>;
+	type ClaimDepositPerByte = ConstU128<CLAIM_DEPOSIT_PER_BYTE>;
+	type MaxMimeTypeLength = ConstU32<16>;
+	type MaxLabelLength = ConstU32<16>;
+	type MaxClaimHistory = ConstU32<2>;
+	type MaxExpiriesPerBlock = ConstU32<2>;
+	type OfferLifetime = ConstU64<5>;
+	type MaxOffersPerBlock = ConstU32<2>;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: (1..=3).map(|account| (account, INITIAL_BALANCE)).collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	storage.into()
 }
@@ -1,12 +1,22 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migrations::{v1::ProofsV0, Migrations},
+	mock::*,
+	ClaimAction, ClaimMetadata, ClaimMetadataOf, ClaimOf, ClaimRecord, Error, Event, HashAlgorithm,
+	Proof, WeightInfo,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
 use sp_runtime::BoundedVec;
 
 #[test]
 fn create_claim() {
 	new_test_ext().execute_with(|| {
 		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
-		let ok = PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let ok = PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
 		assert_ok!(ok);
 	});
 }
@@ -15,8 +25,8 @@ fn create_claim() {
 fn create_claim_failed_when_already_exist() {
 	new_test_ext().execute_with(|| {
 		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
-		let _ = PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
-		let error = PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
+		let error = PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
 		assert_noop!(error, Error::<Test>::AlreadyExist);
 	});
 }
@@ -25,7 +35,7 @@ fn create_claim_failed_when_already_exist() {
 fn revoke_claim() {
 	new_test_ext().execute_with(|| {
 		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
-		let _ = PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
 		let ok = PoexModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone());
 		assert_ok!(ok);
 	});
@@ -44,7 +54,7 @@ fn revoke_claim_failed_when_not_exist() {
 fn revoke_claim_failed_when_error_owner() {
 	new_test_ext().execute_with(|| {
 		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
-		let _ = PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None);
 		let error = PoexModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone());
 		assert_noop!(error, Error::<Test>::ErrorOwner);
 	});
@@ -53,13 +63,20 @@ fn revoke_claim_failed_when_error_owner() {
 #[test]
 fn transfer_claim() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
 		let origin = RuntimeOrigin::signed(1);
-		let _ = PoexModule::create_claim(origin, claim.clone());
+		let _ = PoexModule::create_claim(origin, claim.clone(), None);
 		let origin = RuntimeOrigin::signed(1);
 		let dest = 2u64;
 		let ok = PoexModule::transfer_claim(origin, dest, claim.clone());
 		assert_ok!(ok);
+		System::assert_last_event(Event::ClaimOffered(1, 2, claim.clone(), 6).into());
+		// the claim stays with its owner until the offer is accepted
+		assert_eq!(PoexModule::proofs(&claim).unwrap().owner, 1);
+		assert_eq!(PoexModule::claim_offers(&claim).unwrap().dest, 2);
+		assert_ok!(PoexModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()));
+		assert_eq!(PoexModule::proofs(&claim).unwrap().owner, 2);
 	});
 }
 
@@ -68,7 +85,7 @@ fn transfer_claim_failed_when_same_owner() {
 	new_test_ext().execute_with(|| {
 		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
 		let origin = RuntimeOrigin::signed(1);
-		let _ = PoexModule::create_claim(origin, claim.clone());
+		let _ = PoexModule::create_claim(origin, claim.clone(), None);
 		let origin = RuntimeOrigin::signed(1);
 		let dest = 1u64;
 		let error = PoexModule::transfer_claim(origin, dest, claim.clone());
@@ -92,10 +109,681 @@ fn transfer_claim_failed_when_error_owner() {
 	new_test_ext().execute_with(|| {
 		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
 		let origin = RuntimeOrigin::signed(2);
-		let _ = PoexModule::create_claim(origin, claim.clone());
+		let _ = PoexModule::create_claim(origin, claim.clone(), None);
 		let origin = RuntimeOrigin::signed(1);
 		let dest = 2u64;
 		let error = PoexModule::transfer_claim(origin, dest, claim.clone());
 		assert_noop!(error, Error::<Test>::ErrorOwner);
 	});
 }
+
+#[test]
+fn create_claim_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1, 2]).unwrap();
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None));
+
+		let deposit = CLAIM_DEPOSIT_BASE + 3 * CLAIM_DEPOSIT_PER_BYTE;
+		assert_eq!(PoexModule::claim_deposits(&claim), Some(deposit));
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - deposit);
+	});
+}
+
+#[test]
+fn create_claim_failed_when_not_enough_currency() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let error = PoexModule::create_claim(RuntimeOrigin::signed(4), claim.clone(), None);
+		assert_noop!(error, Error::<Test>::NotEnoughCurrency);
+	});
+}
+
+#[test]
+fn revoke_claim_releases_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None));
+		assert_ok!(PoexModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()));
+
+		assert_eq!(PoexModule::claim_deposits(&claim), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn transfer_claim_moves_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None));
+		assert_ok!(PoexModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim.clone()));
+
+		let deposit = CLAIM_DEPOSIT_BASE + 2 * CLAIM_DEPOSIT_PER_BYTE;
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		assert_ok!(PoexModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - deposit);
+		assert_eq!(Balances::reserved_balance(2), deposit);
+
+		// the new owner gets the deposit back when revoking
+		assert_ok!(PoexModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone()));
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE + deposit);
+	});
+}
+
+#[test]
+fn revoke_claim_without_deposit() {
+	new_test_ext().execute_with(|| {
+		// a claim stored before deposits were introduced
+		let claim: BoundedVec<u8, _> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let proof = Proof {
+			owner: 1,
+			block_number: 0,
+			action: ClaimAction::Migrated,
+			history: Default::default(),
+		};
+		crate::Proofs::<Test>::insert(&claim, proof);
+		assert_ok!(PoexModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+	});
+}
+
+fn metadata() -> ClaimMetadataOf<Test> {
+	ClaimMetadata {
+		mime_type: Some(BoundedVec::try_from(b"text/plain".to_vec()).unwrap()),
+		size: Some(1024),
+		label: None,
+	}
+}
+
+#[test]
+fn create_hash_claim() {
+	new_test_ext().execute_with(|| {
+		let hash = sp_io::hashing::sha2_256(b"document");
+		assert_ok!(PoexModule::create_hash_claim(
+			RuntimeOrigin::signed(1),
+			hash,
+			HashAlgorithm::Sha2_256,
+			metadata()
+		));
+
+		let proof = PoexModule::hash_proofs(hash).unwrap();
+		assert_eq!(proof.owner, 1);
+		assert_eq!(proof.algorithm, HashAlgorithm::Sha2_256);
+		assert_eq!(proof.metadata, metadata());
+
+		let deposit = PoexModule::claim_deposit(32 + metadata().encoded_size());
+		assert_eq!(PoexModule::hash_claim_deposits(hash), Some(deposit));
+		assert_eq!(Balances::reserved_balance(1), deposit);
+	});
+}
+
+#[test]
+fn create_hash_claim_failed_when_already_exist() {
+	new_test_ext().execute_with(|| {
+		let hash = [7; 32];
+		assert_ok!(PoexModule::create_hash_claim(
+			RuntimeOrigin::signed(1),
+			hash,
+			HashAlgorithm::Blake2_256,
+			Default::default()
+		));
+		assert_noop!(
+			PoexModule::create_hash_claim(
+				RuntimeOrigin::signed(2),
+				hash,
+				HashAlgorithm::Keccak256,
+				Default::default()
+			),
+			Error::<Test>::AlreadyExist
+		);
+	});
+}
+
+#[test]
+fn revoke_hash_claim() {
+	new_test_ext().execute_with(|| {
+		let hash = [7; 32];
+		assert_ok!(PoexModule::create_hash_claim(
+			RuntimeOrigin::signed(1),
+			hash,
+			HashAlgorithm::Blake2_256,
+			metadata()
+		));
+		assert_noop!(
+			PoexModule::revoke_hash_claim(RuntimeOrigin::signed(2), hash),
+			Error::<Test>::ErrorOwner
+		);
+		assert_ok!(PoexModule::revoke_hash_claim(RuntimeOrigin::signed(1), hash));
+
+		assert_eq!(PoexModule::hash_proofs(hash), None);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+		assert_noop!(
+			PoexModule::revoke_hash_claim(RuntimeOrigin::signed(1), hash),
+			Error::<Test>::NotExist
+		);
+	});
+}
+
+#[test]
+fn transfer_hash_claim() {
+	new_test_ext().execute_with(|| {
+		let hash = [7; 32];
+		assert_ok!(PoexModule::create_hash_claim(
+			RuntimeOrigin::signed(1),
+			hash,
+			HashAlgorithm::Blake2_256,
+			metadata()
+		));
+		assert_noop!(
+			PoexModule::transfer_hash_claim(RuntimeOrigin::signed(1), 1, hash),
+			Error::<Test>::SameOwner
+		);
+		assert_ok!(PoexModule::transfer_hash_claim(RuntimeOrigin::signed(1), 2, hash));
+
+		assert_eq!(PoexModule::hash_proofs(hash).unwrap().owner, 2);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), PoexModule::hash_claim_deposits(hash).unwrap());
+	});
+}
+
+#[test]
+fn verify_document() {
+	new_test_ext().execute_with(|| {
+		let hash = sp_io::hashing::keccak_256(b"document");
+		assert_eq!(PoexModule::verify_document(hash, HashAlgorithm::Keccak256), None);
+
+		System::set_block_number(3);
+		assert_ok!(PoexModule::create_hash_claim(
+			RuntimeOrigin::signed(1),
+			hash,
+			HashAlgorithm::Keccak256,
+			metadata()
+		));
+
+		let proof = PoexModule::verify_document(hash, HashAlgorithm::Keccak256).unwrap();
+		assert_eq!(proof.owner, 1);
+		assert_eq!(proof.block_number, 3);
+		assert_eq!(proof.metadata.mime_type, Some(b"text/plain".to_vec()));
+		assert_eq!(proof.metadata.size, Some(1024));
+		assert_eq!(proof.metadata.label, None);
+		// the same hash does not verify under another algorithm
+		assert_eq!(PoexModule::verify_document(hash, HashAlgorithm::Blake2_256), None);
+	});
+}
+
+#[test]
+fn transfer_claim_records_history() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		System::set_block_number(2);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None));
+		assert_ok!(PoexModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim.clone()));
+		System::set_block_number(5);
+		assert_ok!(PoexModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()));
+
+		let proof = PoexModule::proofs(&claim).unwrap();
+		assert_eq!(proof.owner, 2);
+		assert_eq!(proof.block_number, 5);
+		assert_eq!(proof.action, ClaimAction::Accepted);
+		assert_eq!(
+			proof.history.into_inner(),
+			vec![ClaimRecord { owner: 1, block_number: 2, action: ClaimAction::Created }]
+		);
+	});
+}
+
+#[test]
+fn claim_history_drops_oldest_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None));
+		// MaxClaimHistory is 2 in the mock
+		for (block, (from, to)) in [(1, 2), (2, 3), (3, 1)].into_iter().enumerate() {
+			System::set_block_number(block as u64 + 1);
+			assert_ok!(PoexModule::transfer_claim(RuntimeOrigin::signed(from), to, claim.clone()));
+			assert_ok!(PoexModule::accept_claim(RuntimeOrigin::signed(to), claim.clone()));
+		}
+
+		let owners: Vec<_> = PoexModule::claim_provenance(&claim)
+			.into_iter()
+			.map(|record| (record.owner, record.block_number))
+			.collect();
+		assert_eq!(owners, vec![(2, 1), (3, 2), (1, 3)]);
+	});
+}
+
+#[test]
+fn claim_provenance() {
+	new_test_ext().execute_with(|| {
+		let claim: BoundedVec<u8, _> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_eq!(PoexModule::claim_provenance(&claim), vec![]);
+		// longer than MaxClaimLength, so never claimed
+		assert_eq!(PoexModule::claim_provenance(&[0; 11]), vec![]);
+
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None));
+		System::set_block_number(4);
+		assert_ok!(PoexModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim.clone()));
+		assert_ok!(PoexModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()));
+
+		assert_eq!(
+			PoexModule::claim_provenance(&claim),
+			vec![
+				ClaimRecord { owner: 1, block_number: 0, action: ClaimAction::Created },
+				ClaimRecord { owner: 2, block_number: 4, action: ClaimAction::Accepted },
+			]
+		);
+	});
+}
+
+#[test]
+fn migrate_v0_to_v1() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<PoexModule>();
+		let claim: BoundedVec<u8, _> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		ProofsV0::<Test>::insert(&claim, (1, 3));
+
+		Migrations::<Test>::on_runtime_upgrade();
+
+		assert_eq!(PoexModule::on_chain_storage_version(), 1);
+		assert_eq!(
+			PoexModule::proofs(&claim),
+			Some(Proof {
+				owner: 1,
+				block_number: 3,
+				action: ClaimAction::Migrated,
+				history: Default::default(),
+			})
+		);
+		assert_ok!(PoexModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim.clone()));
+		assert_ok!(PoexModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()));
+		assert_eq!(PoexModule::claim_provenance(&claim).len(), 2);
+	});
+}
+
+fn claim(bytes: &[u8]) -> ClaimOf<Test> {
+	BoundedVec::try_from(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn create_claim_with_lifetime() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), Some(5)));
+
+		assert_eq!(PoexModule::claim_expiry(claim(&[0, 1])), Some(8));
+		assert_eq!(PoexModule::expiring_claims(8).into_inner(), vec![claim(&[0, 1])]);
+		assert_eq!(PoexModule::sweep_cursor(), Some(3));
+	});
+}
+
+#[test]
+fn create_claim_failed_when_invalid_lifetime() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), Some(0)),
+			Error::<Test>::InvalidLifetime
+		);
+	});
+}
+
+#[test]
+fn create_claim_failed_when_too_many_expiries() {
+	new_test_ext().execute_with(|| {
+		// MaxExpiriesPerBlock is 2 in the mock
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0]), Some(5)));
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[1]), Some(5)));
+		assert_noop!(
+			PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[2]), Some(5)),
+			Error::<Test>::TooManyExpiries
+		);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[2]), Some(6)));
+	});
+}
+
+#[test]
+fn revoke_claim_unschedules_expiry() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), Some(5)));
+		assert_ok!(PoexModule::revoke_claim(RuntimeOrigin::signed(1), claim(&[0, 1])));
+
+		assert_eq!(PoexModule::claim_expiry(claim(&[0, 1])), None);
+		assert!(PoexModule::expiring_claims(5).is_empty());
+	});
+}
+
+#[test]
+fn renew_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), Some(5)));
+		assert_ok!(PoexModule::renew_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), 4));
+
+		assert_eq!(PoexModule::claim_expiry(claim(&[0, 1])), Some(10));
+		assert!(PoexModule::expiring_claims(6).is_empty());
+		assert_eq!(PoexModule::expiring_claims(10).into_inner(), vec![claim(&[0, 1])]);
+		System::assert_last_event(Event::ClaimRenewed(1, claim(&[0, 1]), 10).into());
+	});
+}
+
+#[test]
+fn renew_claim_failed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0]), None));
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[1]), Some(5)));
+
+		assert_noop!(
+			PoexModule::renew_claim(RuntimeOrigin::signed(1), claim(&[2]), 5),
+			Error::<Test>::NotExist
+		);
+		assert_noop!(
+			PoexModule::renew_claim(RuntimeOrigin::signed(2), claim(&[1]), 5),
+			Error::<Test>::ErrorOwner
+		);
+		assert_noop!(
+			PoexModule::renew_claim(RuntimeOrigin::signed(1), claim(&[1]), 0),
+			Error::<Test>::InvalidLifetime
+		);
+		assert_noop!(
+			PoexModule::renew_claim(RuntimeOrigin::signed(1), claim(&[0]), 5),
+			Error::<Test>::NotExpiring
+		);
+
+		// expired but not swept yet
+		System::set_block_number(5);
+		assert_noop!(
+			PoexModule::renew_claim(RuntimeOrigin::signed(1), claim(&[1]), 5),
+			Error::<Test>::ClaimExpired
+		);
+	});
+}
+
+#[test]
+fn on_idle_sweeps_expired_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0]), Some(2)));
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(2), claim(&[1]), Some(3)));
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[2]), None));
+
+		PoexModule::on_idle(3, Weight::MAX);
+		assert_eq!(PoexModule::proofs(claim(&[0])), None);
+		assert_eq!(PoexModule::claim_expiry(claim(&[0])), None);
+		assert!(PoexModule::proofs(claim(&[1])).is_some());
+		System::assert_last_event(Event::ClaimExpired(1, claim(&[0])).into());
+
+		// sweeping skipped for a few blocks catches up later
+		PoexModule::on_idle(7, Weight::MAX);
+		assert_eq!(PoexModule::proofs(claim(&[1])), None);
+		assert!(PoexModule::proofs(claim(&[2])).is_some());
+		assert_eq!(PoexModule::sweep_cursor(), Some(8));
+
+		// deposits of swept claims are released
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE);
+		assert_eq!(Balances::reserved_balance(1), PoexModule::claim_deposits(claim(&[2])).unwrap());
+	});
+}
+
+#[test]
+fn on_idle_sweeps_within_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0]), Some(2)));
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[1]), Some(2)));
+
+		let one_claim = <() as WeightInfo>::expire_claim();
+		assert_eq!(PoexModule::on_idle(2, one_claim), one_claim);
+		assert_eq!(PoexModule::expiring_claims(2).len(), 1);
+		assert_eq!(PoexModule::sweep_cursor(), Some(2));
+
+		PoexModule::on_idle(3, one_claim);
+		assert!(PoexModule::expiring_claims(2).is_empty());
+		assert_eq!(PoexModule::proofs(claim(&[0])), None);
+		assert_eq!(PoexModule::proofs(claim(&[1])), None);
+
+		// nothing is swept without room for a single claim
+		System::set_block_number(4);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[2]), Some(1)));
+		PoexModule::on_idle(5, Weight::zero());
+		assert!(PoexModule::proofs(claim(&[2])).is_some());
+	});
+}
+
+#[test]
+fn expired_claim_cannot_change_hands() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0]), Some(4)));
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[1]), Some(4)));
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[1]), 2));
+
+		// expired at block 5 but not swept yet, the offer itself would still be open
+		System::set_block_number(5);
+		assert_noop!(
+			PoexModule::transfer_claim(RuntimeOrigin::signed(1), 2, claim(&[0])),
+			Error::<Test>::ClaimExpired
+		);
+		assert_noop!(
+			PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[0]), 2),
+			Error::<Test>::ClaimExpired
+		);
+		assert_noop!(
+			PoexModule::accept_claim(RuntimeOrigin::signed(2), claim(&[1])),
+			Error::<Test>::ClaimExpired
+		);
+
+		PoexModule::on_idle(5, Weight::MAX);
+		assert_eq!(PoexModule::proofs(claim(&[1])), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn transfer_claim_failed_when_no_dest() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), None));
+		assert_noop!(
+			PoexModule::transfer_claim(RuntimeOrigin::signed(1), 9, claim(&[0, 1])),
+			Error::<Test>::NoDest
+		);
+	});
+}
+
+#[test]
+fn offer_and_accept_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), None));
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), 2));
+		System::assert_last_event(Event::ClaimOffered(1, 2, claim(&[0, 1]), 6).into());
+		// nothing changes hands until the offer is accepted
+		assert_eq!(PoexModule::proofs(claim(&[0, 1])).unwrap().owner, 1);
+
+		System::set_block_number(3);
+		assert_ok!(PoexModule::accept_claim(RuntimeOrigin::signed(2), claim(&[0, 1])));
+		System::assert_last_event(Event::ClaimAccepted(1, 2, claim(&[0, 1])).into());
+
+		let proof = PoexModule::proofs(claim(&[0, 1])).unwrap();
+		assert_eq!(proof.owner, 2);
+		assert_eq!(proof.block_number, 3);
+		assert_eq!(proof.action, ClaimAction::Accepted);
+		assert_eq!(PoexModule::claim_offers(claim(&[0, 1])), None);
+
+		let deposit = PoexModule::claim_deposits(claim(&[0, 1])).unwrap();
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), deposit);
+	});
+}
+
+#[test]
+fn accept_claim_failed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), None));
+		assert_noop!(
+			PoexModule::accept_claim(RuntimeOrigin::signed(2), claim(&[0, 1])),
+			Error::<Test>::NoOffer
+		);
+
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), 2));
+		assert_noop!(
+			PoexModule::accept_claim(RuntimeOrigin::signed(3), claim(&[0, 1])),
+			Error::<Test>::NotOfferDest
+		);
+
+		// OfferLifetime is 5 in the mock
+		System::set_block_number(6);
+		assert_noop!(
+			PoexModule::accept_claim(RuntimeOrigin::signed(2), claim(&[0, 1])),
+			Error::<Test>::OfferExpired
+		);
+	});
+}
+
+#[test]
+fn offer_claim_failed() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), 2),
+			Error::<Test>::NotExist
+		);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), None));
+		assert_noop!(
+			PoexModule::offer_claim(RuntimeOrigin::signed(2), claim(&[0, 1]), 3),
+			Error::<Test>::ErrorOwner
+		);
+		assert_noop!(
+			PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), 1),
+			Error::<Test>::SameOwner
+		);
+		assert_noop!(
+			PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), 9),
+			Error::<Test>::NoDest
+		);
+	});
+}
+
+#[test]
+fn offer_claim_failed_when_too_many_offers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for i in 0..3 {
+			assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[i]), None));
+		}
+		// MaxOffersPerBlock is 2 in the mock
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[0]), 2));
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[1]), 2));
+		assert_noop!(
+			PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[2]), 2),
+			Error::<Test>::TooManyOffers
+		);
+		// replacing an offer frees its place
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[1]), 3));
+		assert_ok!(PoexModule::cancel_offer(RuntimeOrigin::signed(1), claim(&[1])));
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[2]), 2));
+	});
+}
+
+#[test]
+fn on_idle_sweeps_expired_offers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0]), None));
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[1]), None));
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[0]), 2));
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[1]), 2));
+		assert_eq!(PoexModule::sweep_cursor(), Some(1));
+
+		// a replaced offer moves to the queue of its new expiry
+		System::set_block_number(2);
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[1]), 3));
+		assert_eq!(PoexModule::expiring_offers(6).into_inner(), vec![claim(&[0])]);
+		assert_eq!(PoexModule::expiring_offers(7).into_inner(), vec![claim(&[1])]);
+
+		PoexModule::on_idle(5, Weight::MAX);
+		assert!(PoexModule::claim_offers(claim(&[0])).is_some());
+
+		PoexModule::on_idle(6, Weight::MAX);
+		assert_eq!(PoexModule::claim_offers(claim(&[0])), None);
+		assert!(PoexModule::expiring_offers(6).is_empty());
+		assert!(PoexModule::claim_offers(claim(&[1])).is_some());
+		assert_eq!(PoexModule::sweep_cursor(), Some(7));
+
+		PoexModule::on_idle(7, Weight::MAX);
+		assert_eq!(PoexModule::claim_offers(claim(&[1])), None);
+		// the claims themselves stay with their owner
+		assert_eq!(PoexModule::proofs(claim(&[1])).unwrap().owner, 1);
+	});
+}
+
+#[test]
+fn event_indices_skip_removed_claim_transfer() {
+	// index 2 belonged to `ClaimTransfer`, later events keep the index they were emitted with
+	assert_eq!(Event::HashClaimCreated(1, [0; 32], HashAlgorithm::Sha2_256).encode()[0], 3);
+	assert_eq!(Event::OfferCancelled(1, claim(&[0])).encode()[0], 10);
+}
+
+#[test]
+fn offer_claim_replaces_earlier_offer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), None));
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), 2));
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), 3));
+
+		assert_noop!(
+			PoexModule::accept_claim(RuntimeOrigin::signed(2), claim(&[0, 1])),
+			Error::<Test>::NotOfferDest
+		);
+		assert_ok!(PoexModule::accept_claim(RuntimeOrigin::signed(3), claim(&[0, 1])));
+	});
+}
+
+#[test]
+fn cancel_offer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), None));
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[0, 1]), 2));
+		assert_noop!(
+			PoexModule::cancel_offer(RuntimeOrigin::signed(2), claim(&[0, 1])),
+			Error::<Test>::ErrorOwner
+		);
+
+		assert_ok!(PoexModule::cancel_offer(RuntimeOrigin::signed(1), claim(&[0, 1])));
+		System::assert_last_event(Event::OfferCancelled(1, claim(&[0, 1])).into());
+		assert_noop!(
+			PoexModule::accept_claim(RuntimeOrigin::signed(2), claim(&[0, 1])),
+			Error::<Test>::NoOffer
+		);
+		assert_noop!(
+			PoexModule::cancel_offer(RuntimeOrigin::signed(1), claim(&[0, 1])),
+			Error::<Test>::NoOffer
+		);
+	});
+}
+
+#[test]
+fn offer_dropped_when_claim_moves_or_goes_away() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[0]), None));
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[0]), 2));
+		assert_ok!(PoexModule::transfer_claim(RuntimeOrigin::signed(1), 3, claim(&[0])));
+		assert_ok!(PoexModule::accept_claim(RuntimeOrigin::signed(3), claim(&[0])));
+		assert_eq!(PoexModule::claim_offers(claim(&[0])), None);
+
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[1]), None));
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[1]), 2));
+		assert_ok!(PoexModule::revoke_claim(RuntimeOrigin::signed(1), claim(&[1])));
+		assert_eq!(PoexModule::claim_offers(claim(&[1])), None);
+
+		assert_ok!(PoexModule::create_claim(RuntimeOrigin::signed(1), claim(&[2]), Some(2)));
+		assert_ok!(PoexModule::offer_claim(RuntimeOrigin::signed(1), claim(&[2]), 2));
+		PoexModule::on_idle(3, Weight::MAX);
+		assert_eq!(PoexModule::claim_offers(claim(&[2])), None);
+	});
+}
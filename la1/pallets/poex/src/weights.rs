@@ -1,28 +1,13 @@
-
-//! Autogenerated weights for `pallet_poex`
+//! Weights for `pallet_poex`.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2024-01-28, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `DESKTOP-THUNDER0`, CPU: `12th Gen Intel(R) Core(TM) i7-12700H`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
-
-// Executed Command:
-// ./target/release/node-template
-// benchmark
-// pallet
-// --chain
-// dev
-// --wasm-execution
-// compiled
-// --pallet
-// pallet_poex
-// --extrinsic
-// *
-// --output
-// ./pallets/poex/src/weights.rs
-// --template
-// .maintain/frame-weight-template.hbs
+//! These values were NOT produced by a benchmark run. The storage accesses are read off each
+//! call, and the base times are hand estimates.
+//! Replace this file with real numbers by building the node with `--features
+//! runtime-benchmarks` and running:
+//!
+//! ./target/release/node-template benchmark pallet --chain dev --wasm-execution compiled \
+//!     --pallet pallet_poex --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output ./pallets/poex/src/weights.rs --template .maintain/frame-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -37,42 +22,209 @@ pub trait WeightInfo {
 	fn create_claim() -> Weight;
 	fn revoke_claim() -> Weight;
 	fn transfer_claim() -> Weight;
+	fn create_hash_claim() -> Weight;
+	fn revoke_hash_claim() -> Weight;
+	fn transfer_hash_claim() -> Weight;
+	fn renew_claim() -> Weight;
+	fn expire_claim() -> Weight;
+	fn offer_claim() -> Weight;
+	fn accept_claim() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn expire_offer() -> Weight;
 }
 
-/// Weights for `pallet_poex` using the Substrate node and recommended hardware.
+/// Estimated weights for `pallet_poex`, see the module docs.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `PoexModule::Proofs` (r:1 w:1)
-	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringClaims` (r:1 w:1)
+	/// Proof: `PoexModule::ExpiringClaims` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:0 w:1)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::SweepCursor` (r:1 w:0)
+	/// Proof: `PoexModule::SweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 2479, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimDeposits` (r:0 w:1)
+	/// Proof: `PoexModule::ClaimDeposits` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
 	fn create_claim() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `109`
-		//  Estimated: `3526`
-		// Minimum execution time: 10_648_000 picoseconds.
-		Weight::from_parts(11_365_000, 3526)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `4119`
+		Weight::from_parts(39_164_000, 4119)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `PoexModule::Proofs` (r:1 w:1)
-	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringClaims` (r:1 w:1)
+	/// Proof: `PoexModule::ExpiringClaims` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimDeposits` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimDeposits` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ExpiringOffers` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
 	fn revoke_claim() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `197`
-		//  Estimated: `3526`
-		// Minimum execution time: 11_644_000 picoseconds.
-		Weight::from_parts(11_840_000, 3526)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `4119`
+		Weight::from_parts(47_000_000, 4119)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
-	/// Storage: `PoexModule::Proofs` (r:1 w:1)
-	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::Proofs` (r:1 w:0)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:1 w:0)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringOffers` (r:2 w:2)
+	/// Proof: `PoexModule::ExpiringOffers` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::SweepCursor` (r:1 w:1)
+	/// Proof: `PoexModule::SweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 2479, mode: `MaxEncodedLen`)
 	fn transfer_claim() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `197`
-		//  Estimated: `3526`
-		// Minimum execution time: 12_913_000 picoseconds.
-		Weight::from_parts(13_459_000, 3526)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		//  Estimated: `6062`
+		Weight::from_parts(28_000_000, 6062)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `PoexModule::HashProofs` (r:1 w:1)
+	/// Proof: `PoexModule::HashProofs` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::HashClaimDeposits` (r:0 w:1)
+	/// Proof: `PoexModule::HashClaimDeposits` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn create_hash_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3691`
+		Weight::from_parts(34_712_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PoexModule::HashProofs` (r:1 w:1)
+	/// Proof: `PoexModule::HashProofs` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::HashClaimDeposits` (r:1 w:1)
+	/// Proof: `PoexModule::HashClaimDeposits` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn revoke_hash_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3691`
+		Weight::from_parts(36_254_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PoexModule::HashProofs` (r:1 w:1)
+	/// Proof: `PoexModule::HashProofs` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::HashClaimDeposits` (r:1 w:0)
+	/// Proof: `PoexModule::HashClaimDeposits` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn transfer_hash_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6196`
+		Weight::from_parts(49_603_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PoexModule::Proofs` (r:1 w:0)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringClaims` (r:2 w:2)
+	/// Proof: `PoexModule::ExpiringClaims` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::SweepCursor` (r:1 w:0)
+	/// Proof: `PoexModule::SweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 2479, mode: `MaxEncodedLen`)
+	fn renew_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6062`
+		Weight::from_parts(25_630_000, 6062)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PoexModule::Proofs` (r:1 w:1)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimDeposits` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimDeposits` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:0 w:1)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ExpiringOffers` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	fn expire_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4119`
+		Weight::from_parts(36_000_000, 4119)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::Proofs` (r:1 w:0)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:1 w:0)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringOffers` (r:2 w:2)
+	/// Proof: `PoexModule::ExpiringOffers` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::SweepCursor` (r:1 w:1)
+	/// Proof: `PoexModule::SweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 2479, mode: `MaxEncodedLen`)
+	fn offer_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6062`
+		Weight::from_parts(28_000_000, 6062)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `PoexModule::ClaimOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::Proofs` (r:1 w:1)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:1 w:0)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimDeposits` (r:1 w:0)
+	/// Proof: `PoexModule::ClaimDeposits` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ExpiringOffers` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	fn accept_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6196`
+		Weight::from_parts(59_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `PoexModule::Proofs` (r:1 w:0)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ExpiringOffers` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	fn cancel_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4119`
+		Weight::from_parts(20_000_000, 4119)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `PoexModule::ClaimOffers` (r:0 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	fn expire_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(4_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
@@ -80,36 +232,194 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `PoexModule::Proofs` (r:1 w:1)
-	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringClaims` (r:1 w:1)
+	/// Proof: `PoexModule::ExpiringClaims` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:0 w:1)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::SweepCursor` (r:1 w:0)
+	/// Proof: `PoexModule::SweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 2479, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimDeposits` (r:0 w:1)
+	/// Proof: `PoexModule::ClaimDeposits` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
 	fn create_claim() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `109`
-		//  Estimated: `3526`
-		// Minimum execution time: 10_648_000 picoseconds.
-		Weight::from_parts(11_365_000, 3526)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `4119`
+		Weight::from_parts(39_164_000, 4119)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `PoexModule::Proofs` (r:1 w:1)
-	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringClaims` (r:1 w:1)
+	/// Proof: `PoexModule::ExpiringClaims` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimDeposits` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimDeposits` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ExpiringOffers` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
 	fn revoke_claim() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `197`
-		//  Estimated: `3526`
-		// Minimum execution time: 11_644_000 picoseconds.
-		Weight::from_parts(11_840_000, 3526)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `4119`
+		Weight::from_parts(47_000_000, 4119)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
-	/// Storage: `PoexModule::Proofs` (r:1 w:1)
-	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::Proofs` (r:1 w:0)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:1 w:0)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringOffers` (r:2 w:2)
+	/// Proof: `PoexModule::ExpiringOffers` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::SweepCursor` (r:1 w:1)
+	/// Proof: `PoexModule::SweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 2479, mode: `MaxEncodedLen`)
 	fn transfer_claim() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `197`
-		//  Estimated: `3526`
-		// Minimum execution time: 12_913_000 picoseconds.
-		Weight::from_parts(13_459_000, 3526)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		//  Estimated: `6062`
+		Weight::from_parts(28_000_000, 6062)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `PoexModule::HashProofs` (r:1 w:1)
+	/// Proof: `PoexModule::HashProofs` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::HashClaimDeposits` (r:0 w:1)
+	/// Proof: `PoexModule::HashClaimDeposits` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn create_hash_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3691`
+		Weight::from_parts(34_712_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PoexModule::HashProofs` (r:1 w:1)
+	/// Proof: `PoexModule::HashProofs` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::HashClaimDeposits` (r:1 w:1)
+	/// Proof: `PoexModule::HashClaimDeposits` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn revoke_hash_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3691`
+		Weight::from_parts(36_254_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PoexModule::HashProofs` (r:1 w:1)
+	/// Proof: `PoexModule::HashProofs` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::HashClaimDeposits` (r:1 w:0)
+	/// Proof: `PoexModule::HashClaimDeposits` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn transfer_hash_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6196`
+		Weight::from_parts(49_603_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PoexModule::Proofs` (r:1 w:0)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringClaims` (r:2 w:2)
+	/// Proof: `PoexModule::ExpiringClaims` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::SweepCursor` (r:1 w:0)
+	/// Proof: `PoexModule::SweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 2479, mode: `MaxEncodedLen`)
+	fn renew_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6062`
+		Weight::from_parts(25_630_000, 6062)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PoexModule::Proofs` (r:1 w:1)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimDeposits` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimDeposits` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:0 w:1)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ExpiringOffers` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	fn expire_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4119`
+		Weight::from_parts(36_000_000, 4119)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::Proofs` (r:1 w:0)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:1 w:0)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringOffers` (r:2 w:2)
+	/// Proof: `PoexModule::ExpiringOffers` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::SweepCursor` (r:1 w:1)
+	/// Proof: `PoexModule::SweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 2479, mode: `MaxEncodedLen`)
+	fn offer_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6062`
+		Weight::from_parts(28_000_000, 6062)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `PoexModule::ClaimOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::Proofs` (r:1 w:1)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimExpiry` (r:1 w:0)
+	/// Proof: `PoexModule::ClaimExpiry` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimDeposits` (r:1 w:0)
+	/// Proof: `PoexModule::ClaimDeposits` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ExpiringOffers` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	fn accept_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `6196`
+		Weight::from_parts(59_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `PoexModule::Proofs` (r:1 w:0)
+	/// Proof: `PoexModule::Proofs` (`max_values`: None, `max_size`: Some(654), added: 3129, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ClaimOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PoexModule::ExpiringOffers` (r:1 w:1)
+	/// Proof: `PoexModule::ExpiringOffers` (`max_values`: None, `max_size`: Some(589), added: 3064, mode: `MaxEncodedLen`)
+	fn cancel_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4119`
+		Weight::from_parts(20_000_000, 4119)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `PoexModule::ClaimOffers` (r:0 w:1)
+	/// Proof: `PoexModule::ClaimOffers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	fn expire_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(4_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-}
\ No newline at end of file
+}
@@ -273,15 +273,40 @@ impl pallet_template::Config for Runtime {
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub ClaimDepositBase: Balance = EXISTENTIAL_DEPOSIT;
+	pub ClaimDepositPerByte: Balance = EXISTENTIAL_DEPOSIT / 10;
+}
+
 impl pallet_poex::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_poex::weights::SubstrateWeight<Runtime>;
 	type MaxClaimLength = ConstU32<8>;
+	type Currency = Balances;
+	type ClaimDepositBase = ClaimDepositBase;
+	type ClaimDepositPerByte = ClaimDepositPerByte;
+	type MaxMimeTypeLength = ConstU32<64>;
+	type MaxLabelLength = ConstU32<64>;
+	type MaxClaimHistory = ConstU32<16>;
+	type MaxExpiriesPerBlock = ConstU32<64>;
+	type OfferLifetime = ConstU32<DAYS>;
+	type MaxOffersPerBlock = ConstU32<64>;
 }
 
 parameter_types! {
 	pub KittyPalletId:PalletId =PalletId(*b"kittiesX");
+	// creation price before collections, recorded for kitties moved into the default collection
+	// and used as its mint price on chains that create it at genesis
 	pub KittyCreatePrice: Balance = EXISTENTIAL_DEPOSIT *10;
+	pub KittyMarketplaceFee: Perbill = Perbill::from_percent(2);
+	pub KittyMutationRate: Perbill = Perbill::from_percent(3);
+	pub KittyMetadataDeposit: Balance = EXISTENTIAL_DEPOSIT * 2;
+	pub KittyBreedCooldown: BlockNumber = 10 * MINUTES;
+	pub KittyBurnRefundRatio: Perbill = Perbill::from_percent(50);
+	pub KittyMaxRoyalty: Perbill = Perbill::from_percent(10);
+	// Aura has no VRF, so kitties draw on the collective flip, which serves material from
+	// the last 81 blocks; committed mints must wait past that window to be unpredictable.
+	pub const KittyRevealDelay: BlockNumber = 100;
 }
 
 impl pallet_kittiesx::Config for Runtime {
@@ -289,8 +314,21 @@ impl pallet_kittiesx::Config for Runtime {
 	type WeightInfo = pallet_kittiesx::weights::SubstrateWeight<Runtime>;
 	type Randomness = Randomness;
 	type Currency = Balances;
-	type KittyCreatePrice = KittyCreatePrice;
 	type PalletId = KittyPalletId;
+	type MaxKittiesOwned = ConstU32<256>;
+	type MarketplaceFee = KittyMarketplaceFee;
+	type MaxAuctionsPerBlock = ConstU32<64>;
+	type MutationRate = KittyMutationRate;
+	type MetadataDeposit = KittyMetadataDeposit;
+	type BreedCooldown = KittyBreedCooldown;
+	type BurnRefundRatio = KittyBurnRefundRatio;
+	type RevealDelay = KittyRevealDelay;
+	type MaxMintsPerBlock = ConstU32<64>;
+	type MigrationBatchSize = ConstU32<256>;
+	type MaxMetadataLength = ConstU32<256>;
+	type MaxBatchSize = ConstU32<64>;
+	type MaxRoyalty = KittyMaxRoyalty;
+	type MaxLeasesPerBlock = ConstU32<64>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -337,6 +375,12 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations run on the next runtime upgrade.
+pub type Migrations = (
+	pallet_kittiesx::migrations::Migrations<Runtime, KittyCreatePrice>,
+	pallet_poex::migrations::Migrations<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -344,6 +388,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -360,6 +405,7 @@ mod benches {
 		[pallet_sudo, Sudo]
 		[pallet_template, TemplateModule]
 		[pallet_poex,PoexModule]
+		[pallet_kittiesx, KittiesxModule]
 	);
 }
 
@@ -487,6 +533,50 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_kittiesx_runtime_api::KittiesApi<Block, AccountId, Balance> for Runtime {
+		fn kitty_traits(kitty_id: pallet_kittiesx::CollectionKittyId) -> Option<pallet_kittiesx::KittyTraits> {
+			KittiesxModule::kitty_traits(kitty_id)
+		}
+
+		fn kitty_ancestry(kitty_id: pallet_kittiesx::CollectionKittyId, depth: u32) -> Vec<pallet_kittiesx::KittyLineage> {
+			KittiesxModule::kitty_ancestry(kitty_id, depth)
+		}
+
+		fn kitty(kitty_id: pallet_kittiesx::CollectionKittyId) -> Option<pallet_kittiesx::KittyInfo<AccountId, Balance>> {
+			KittiesxModule::kitty_info(kitty_id)
+		}
+
+		fn kitties_by_owner(owner: AccountId, start: u32, limit: u32) -> Vec<pallet_kittiesx::CollectionKittyId> {
+			KittiesxModule::kitties_by_owner(&owner, start, limit)
+		}
+
+		fn kitties_on_sale(
+			start_after: Option<pallet_kittiesx::CollectionKittyId>,
+			limit: u32,
+		) -> Vec<(pallet_kittiesx::CollectionKittyId, Balance)> {
+			KittiesxModule::kitties_on_sale(start_after, limit)
+		}
+
+		fn kitty_parents(
+			kitty_id: pallet_kittiesx::CollectionKittyId,
+		) -> Option<(pallet_kittiesx::CollectionKittyId, pallet_kittiesx::CollectionKittyId)> {
+			KittiesxModule::kitty_parents(kitty_id)
+		}
+	}
+
+	impl pallet_poex_runtime_api::PoexApi<Block, AccountId, BlockNumber> for Runtime {
+		fn verify_document(
+			hash: pallet_poex::DocumentHash,
+			algorithm: pallet_poex::HashAlgorithm,
+		) -> Option<pallet_poex::DocumentProof<AccountId, BlockNumber>> {
+			PoexModule::verify_document(hash, algorithm)
+		}
+
+		fn claim_provenance(claim: Vec<u8>) -> Vec<pallet_poex::ClaimRecord<AccountId, BlockNumber>> {
+			PoexModule::claim_provenance(&claim)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,